@@ -0,0 +1,148 @@
+//! Build script which hashes the embedded static files, so that they can be served with
+//! ETags without hashing them at runtime, and precompresses them with gzip and brotli.
+
+extern crate brotli;
+extern crate flate2;
+extern crate tiny_keccak;
+
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use tiny_keccak::Keccak;
+
+/// Finds all files within the specified directory, returning their paths relative to the
+/// root with forward slashes.
+fn find_files(root: &Path, dir: &Path, found: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).expect("Unable to read static directory") {
+        let path = entry.expect("Unable to read static directory").path();
+
+        if path.is_dir() {
+            find_files(root, &path, found);
+        } else {
+            let name = path
+                .strip_prefix(root)
+                .expect("Static file outside of static directory")
+                .iter()
+                .map(|x| x.to_str().expect("Static filename isn't valid UTF-8"))
+                .collect::<Vec<_>>()
+                .join("/");
+            found.push(name);
+        }
+    }
+}
+
+/// Hashes some contents using SHA3-256, returning the first half of the hash in hex form.
+fn hash_contents(contents: &[u8]) -> String {
+    let mut hasher: Keccak = Keccak::new_sha3_256();
+    hasher.update(contents);
+
+    let mut hash: [u8; 32] = [0; 32];
+    hasher.finalize(&mut hash);
+
+    hash[..16].iter().map(|x| format!("{:02x}", x)).collect()
+}
+
+/// Compresses some contents with the specified encoding, at the highest quality available.
+fn compress(contents: &[u8], encoding: &str) -> Vec<u8> {
+    let mut output = Vec::new();
+
+    match encoding {
+        "gzip" => {
+            let mut encoder = GzEncoder::new(&mut output, Compression::best());
+            encoder
+                .write_all(contents)
+                .expect("Unable to gzip static file");
+            encoder.finish().expect("Unable to gzip static file");
+        }
+        "br" => {
+            let mut encoder = brotli::CompressorWriter::new(&mut output, 4096, 11, 22);
+            encoder
+                .write_all(contents)
+                .expect("Unable to brotli compress static file");
+            // The rest of the output is written once the encoder is dropped
+        }
+        _ => panic!("Unknown encoding {}", encoding),
+    }
+
+    output
+}
+
+fn main() {
+    let root = Path::new("static");
+    println!("cargo:rerun-if-changed=static");
+
+    let mut files = Vec::new();
+    find_files(root, root, &mut files);
+    files.sort();
+
+    let out_dir = env::var("OUT_DIR").expect("No output directory specified");
+    let out_dir = Path::new(&out_dir);
+
+    let mut hashes = String::new();
+    let mut variants = String::new();
+
+    for name in &files {
+        let mut contents = Vec::new();
+        File::open(root.join(name))
+            .and_then(|mut file| file.read_to_end(&mut contents))
+            .expect("Unable to read static file");
+
+        hashes += &format!(
+            "        {:?} => Some({:?}),\n",
+            name,
+            hash_contents(&contents)
+        );
+
+        for &(encoding, extension) in &[("br", "br"), ("gzip", "gz")] {
+            let compressed = compress(&contents, encoding);
+
+            // Files which don't compress well are always sent as they are
+            if compressed.len() >= contents.len() {
+                continue;
+            }
+
+            let path = out_dir
+                .join("compressed")
+                .join(format!("{}.{}", name, extension));
+            fs::create_dir_all(path.parent().unwrap())
+                .and_then(|_| File::create(&path))
+                .and_then(|mut file| file.write_all(&compressed))
+                .expect("Unable to write compressed static file");
+
+            variants += &format!(
+                "        ({:?}, {:?}) => Some(include_bytes!({:?})),\n",
+                name,
+                encoding,
+                path.to_str().expect("Output path isn't valid UTF-8")
+            );
+        }
+    }
+
+    let mut output = String::new();
+    output += "/// Returns the hash of an embedded static file, as computed at build time.\n";
+    output += "pub fn asset_hash(name: &str) -> Option<&'static str> {\n";
+    output += "    match name {\n";
+    output += &hashes;
+    output += "        _ => None,\n";
+    output += "    }\n";
+    output += "}\n\n";
+    output += "/// Returns an embedded static file compressed with the specified encoding, if it\n";
+    output += "/// compresses well enough to have been compressed at build time.\n";
+    output += "pub fn asset_variant(name: &str, encoding: &str) -> Option<&'static [u8]> {\n";
+    output += "    match (name, encoding) {\n";
+    output += &variants;
+    output += "        _ => None,\n";
+    output += "    }\n";
+    output += "}\n";
+
+    File::create(out_dir.join("asset_info.rs"))
+        .and_then(|mut file| file.write_all(output.as_bytes()))
+        .expect("Unable to write asset information");
+}
@@ -15,10 +15,8 @@ macro_rules! include_files_as_assets {
 include_files_as_assets!(
     FILES,
     "../static/",
-    "css/agate.css",
     "css/bulma.min.css",
     "css/main.css",
-    "js/highlight.pack.js",
     "js/manage.js",
     "js/vue.min.js"
 );
@@ -27,12 +25,19 @@ include_files_as_assets!(
     TEMPLATES,
     "../templates/",
     "footer.hbs",
+    "gallery.hbs",
     "header.hbs",
     "index.hbs",
+    "link.hbs",
     "manage.hbs",
-    "text.hbs"
+    "preview.hbs",
+    "text.hbs",
+    "unlock.hbs"
 );
 
+// Hashes and precompressed variants of the static files, generated by the build script
+include!(concat!(env!("OUT_DIR"), "/asset_info.rs"));
+
 /// Returns the contents of a file from the given list.
 pub fn get_file(
     table: &phf::Map<&'static str, &'static [u8]>,
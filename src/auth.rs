@@ -2,6 +2,13 @@
 
 use iron::typemap;
 
+use tiny_keccak::Keccak;
+
+use base64;
+
+use rand;
+use rand::Rng;
+
 /// Stores information about a logged in user.
 #[derive(Serialize, Deserialize)]
 pub struct User {
@@ -14,3 +21,104 @@ pub struct SessionStore {}
 impl typemap::Key for SessionStore {
     type Value = User;
 }
+
+/// Hashes a secret (such as a password) using SHA3-256, prefixed by the specified salt.
+pub fn hash_secret(salt: &[u8], secret: &[u8]) -> [u8; 32] {
+    let mut hasher: Keccak = Keccak::new_sha3_256();
+    hasher.update(salt);
+    hasher.update(secret);
+
+    let mut output: [u8; 32] = [0; 32];
+    hasher.finalize(&mut output);
+
+    output
+}
+
+/// Generates a stable identifier for an API key, which can be stored without revealing the key.
+pub fn api_key_id(key: &str) -> String {
+    base64::encode_config(
+        &hash_secret(&[], key.as_bytes())[..12],
+        base64::URL_SAFE_NO_PAD,
+    )
+}
+
+/// Hashes a secret with a newly generated random salt, returning it in the form "salt$hash".
+pub fn hash_secret_salted(secret: &str) -> String {
+    let mut salt: [u8; 16] = [0; 16];
+    rand::thread_rng().fill(&mut salt);
+
+    let salt = base64::encode(&salt);
+    let hash = base64::encode(&hash_secret(salt.as_bytes(), secret.as_bytes()));
+
+    format!("{}${}", salt, hash)
+}
+
+/// Generates a random secret token, such as for deleting an upload without logging in.
+pub fn generate_token() -> String {
+    let mut token: [u8; 24] = [0; 24];
+    rand::thread_rng().fill(&mut token);
+
+    base64::encode_config(&token, base64::URL_SAFE_NO_PAD)
+}
+
+/// Checks a secret against a hash generated by hash_secret_salted.
+pub fn verify_secret_salted(hashed: &str, secret: &str) -> bool {
+    let mut parts = hashed.splitn(2, '$');
+
+    match (parts.next(), parts.next()) {
+        (Some(salt), Some(hash)) => {
+            let expected = base64::encode(&hash_secret(salt.as_bytes(), secret.as_bytes()));
+            constant_time_eq(hash.as_bytes(), expected.as_bytes())
+        }
+        _ => false,
+    }
+}
+
+/// Compares two byte strings without returning early, so as not to leak where they differ.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut difference = 0;
+    for (x, y) in a.iter().zip(b.iter()) {
+        difference |= x ^ y;
+    }
+
+    difference == 0
+}
+
+/// Generates a token which grants access to a password protected file until the specified
+/// UNIX timestamp. Tokens are signed with the server's key, and are invalidated if the file's
+/// password changes.
+pub fn unlock_token(key: &str, name: &str, password_hash: &str, expires: i64) -> String {
+    let message = format!("{}\n{}\n{}", name, password_hash, expires);
+    let signature = hash_secret(key.as_bytes(), message.as_bytes());
+
+    format!(
+        "{}-{}",
+        expires,
+        base64::encode_config(&signature, base64::URL_SAFE_NO_PAD)
+    )
+}
+
+/// Checks a token generated by unlock_token, ensuring that it hasn't expired.
+pub fn verify_unlock_token(
+    key: &str,
+    name: &str,
+    password_hash: &str,
+    token: &str,
+    now: i64,
+) -> bool {
+    let expires = match token.splitn(2, '-').next().map(|x| x.parse::<i64>()) {
+        Some(Ok(expires)) => expires,
+        _ => return false,
+    };
+
+    if expires < now {
+        return false;
+    }
+
+    let expected = unlock_token(key, name, password_hash, expires);
+    constant_time_eq(token.as_bytes(), expected.as_bytes())
+}
@@ -0,0 +1,109 @@
+//! Content-addressed storage for uploaded files. Identical uploads share a single blob on
+//! disk, with each upload being a hard link to that blob. The link count of a blob acts as its
+//! reference count, so a blob is removed once the last upload referring to it is gone.
+
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::os::unix::fs::MetadataExt;
+use std::path::Path;
+use std::path::PathBuf;
+
+use tiny_keccak::Keccak;
+
+use rand;
+use rand::Rng;
+
+/// Hashes the contents of a file using SHA3-256, returning the hash in hex form.
+pub fn hash_file(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+
+    let mut hasher: Keccak = Keccak::new_sha3_256();
+    let mut buffer = [0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+
+        hasher.update(&buffer[..read]);
+    }
+
+    let mut hash: [u8; 32] = [0; 32];
+    hasher.finalize(&mut hash);
+
+    Ok(hash.iter().map(|x| format!("{:02x}", x)).collect())
+}
+
+/// Returns the path of the blob with the specified hash.
+pub fn blob_path(root: &str, hash: &str) -> PathBuf {
+    Path::new(root).join("blobs").join(hash)
+}
+
+/// Stores the contents of the source file at the target, sharing the contents with any
+/// identical files which are already stored. Returns the hash of the contents.
+pub fn store(root: &str, source: &Path, target: &Path) -> io::Result<String> {
+    let hash = hash_file(source)?;
+    let blob = blob_path(root, &hash);
+
+    fs::create_dir_all(Path::new(root).join("blobs"))?;
+
+    // The blob may be released between creating it and linking to it, so try again if so
+    for _ in 0..2 {
+        if !blob.exists() {
+            // Copy to a temporary file first, so that partially copied blobs are never used
+            let temp = Path::new(root).join("blobs").join(format!(
+                "{}.{}.tmp",
+                hash,
+                rand::thread_rng().gen::<u64>()
+            ));
+
+            fs::copy(source, &temp)?;
+
+            let result = fs::hard_link(&temp, &blob);
+            fs::remove_file(&temp)?;
+
+            match result {
+                // Another upload of the same contents may have won the race
+                Err(ref e) if e.kind() == ErrorKind::AlreadyExists => (),
+                Err(e) => return Err(e),
+                Ok(_) => (),
+            }
+        }
+
+        match fs::hard_link(&blob, target) {
+            Ok(_) => return Ok(hash),
+            Err(ref e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(io::Error::new(
+        ErrorKind::Other,
+        format!("Blob {} kept disappearing", hash),
+    ))
+}
+
+/// Releases a reference to the blob with the specified hash, removing the blob if nothing
+/// else refers to it. This should be called after the referring file has been removed.
+pub fn release(root: &str, hash: &str) -> io::Result<()> {
+    let blob = blob_path(root, hash);
+
+    let metadata = match fs::metadata(&blob) {
+        Ok(metadata) => metadata,
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    if metadata.nlink() > 1 {
+        return Ok(());
+    }
+
+    match fs::remove_file(&blob) {
+        Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
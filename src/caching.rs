@@ -0,0 +1,165 @@
+//! Helpers for conditional requests and caching headers, so that clients don't repeatedly
+//! fetch content which hasn't changed.
+
+use config::ConfigContainer;
+
+use iron::headers::ETag;
+use iron::headers::EntityTag;
+use iron::headers::HttpDate;
+use iron::headers::IfModifiedSince;
+use iron::headers::IfNoneMatch;
+use iron::headers::IfRange;
+use iron::headers::LastModified;
+use iron::headers::Range;
+use iron::prelude::*;
+use iron::status;
+
+use persistent;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Utc;
+
+/// The classes of content which are served, each with their own Cache-Control header.
+pub enum ContentClass {
+    Upload,
+    Asset,
+    Page,
+    /// Password protected or view limited content, which must never be stored by caches.
+    Private,
+}
+
+/// Validators which identify a specific version of some content.
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<DateTime<FixedOffset>>,
+}
+
+impl Validators {
+    /// Validators for content which can't be identified, and so is never considered fresh.
+    pub fn none() -> Validators {
+        Validators {
+            etag: None,
+            last_modified: None,
+        }
+    }
+
+    fn entity_tag(&self) -> Option<EntityTag> {
+        self.etag
+            .as_ref()
+            .map(|etag| EntityTag::strong(etag.to_owned()))
+    }
+
+    fn http_date(&self) -> Option<HttpDate> {
+        self.last_modified.and_then(|date| {
+            date.with_timezone(&Utc)
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string()
+                .parse()
+                .ok()
+        })
+    }
+
+    /// Checks if the client making a request already has this version of the content, as
+    /// indicated by its If-None-Match or If-Modified-Since headers.
+    pub fn is_fresh(&self, req: &Request) -> bool {
+        // If-None-Match takes precedence when both are specified
+        match (req.headers.get::<IfNoneMatch>(), self.entity_tag()) {
+            (Some(&IfNoneMatch::Any), Some(_)) => return true,
+            (Some(&IfNoneMatch::Items(ref tags)), Some(ref etag)) => {
+                return tags.iter().any(|tag| tag.weak_eq(etag))
+            }
+            (Some(_), None) => return false,
+            (None, _) => (),
+        }
+
+        match (req.headers.get::<IfModifiedSince>(), self.last_modified) {
+            (Some(&IfModifiedSince(ref since)), Some(modified)) => {
+                modified.timestamp() <= since.0.to_timespec().sec
+            }
+            _ => false,
+        }
+    }
+
+    /// Checks if a range of the content may be sent, as opposed to all of it, following the
+    /// If-Range header of a request.
+    pub fn range_applies(&self, req: &Request) -> bool {
+        match req.headers.get::<IfRange>() {
+            Some(&IfRange::EntityTag(ref tag)) => match self.entity_tag() {
+                Some(ref etag) => tag.strong_eq(etag),
+                None => false,
+            },
+            Some(&IfRange::Date(ref date)) => match self.last_modified {
+                Some(modified) => modified.timestamp() == date.0.to_timespec().sec,
+                None => false,
+            },
+            None => true,
+        }
+    }
+
+    /// Sets the ETag and Last-Modified headers of a response.
+    fn apply(&self, response: &mut Response) {
+        match self.entity_tag() {
+            Some(etag) => response.headers.set(ETag(etag)),
+            None => (),
+        }
+
+        match self.http_date() {
+            Some(date) => response.headers.set(LastModified(date)),
+            None => (),
+        }
+    }
+}
+
+/// Finds the configured Cache-Control header value for a class of content.
+fn cache_control(req: &mut Request, class: ContentClass) -> String {
+    let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+    let config = &arc.as_ref().cache_control;
+
+    let (configured, default) = match class {
+        ContentClass::Upload => (&config.uploads, "public, max-age=3600"),
+        ContentClass::Asset => (&config.assets, "public, max-age=86400"),
+        ContentClass::Page => (&config.pages, "no-cache"),
+        ContentClass::Private => return "private, no-store".to_string(),
+    };
+
+    configured.clone().unwrap_or_else(|| default.to_string())
+}
+
+/// Builds a response for some content, answering with 304 Not Modified instead if the client
+/// already has the version identified by the validators. Ranges are only honoured if they
+/// refer to this version.
+pub fn cached_response<F>(
+    req: &mut Request,
+    validators: &Validators,
+    class: ContentClass,
+    build: F,
+) -> IronResult<Response>
+where
+    F: FnOnce(&mut Request) -> IronResult<Response>,
+{
+    let cache_control = cache_control(req, class);
+
+    let mut response = if validators.is_fresh(req) {
+        Response::with(status::NotModified)
+    } else {
+        if !validators.range_applies(req) {
+            req.headers.remove::<Range>();
+        }
+
+        build(req)?
+    };
+
+    // Errors shouldn't be cached as if they were the content
+    match response.status {
+        Some(status) if status.is_success() || status == status::NotModified => {
+            validators.apply(&mut response);
+            response
+                .headers
+                .set_raw("Cache-Control", vec![cache_control.into_bytes()]);
+        }
+        _ => (),
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,172 @@
+//! Compresses responses with gzip or brotli, for clients which accept them.
+
+use std::io;
+use std::io::Write;
+
+use iron::headers::AcceptEncoding;
+use iron::headers::ContentEncoding;
+use iron::headers::ContentLength;
+use iron::headers::Encoding;
+use iron::modifier::Modifier;
+use iron::prelude::*;
+use iron::status;
+use iron::typemap::Key;
+use iron::AfterMiddleware;
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use brotli;
+
+/// The brotli quality used for dynamic responses, trading off size for speed.
+const BROTLI_QUALITY: u32 = 5;
+
+/// The content codings which responses can be compressed with.
+#[derive(Copy, Clone)]
+pub enum ContentCoding {
+    Brotli,
+    Gzip,
+}
+
+impl ContentCoding {
+    /// Returns the name of this coding, as used in HTTP headers.
+    pub fn name(&self) -> &'static str {
+        match *self {
+            ContentCoding::Brotli => "br",
+            ContentCoding::Gzip => "gzip",
+        }
+    }
+
+    /// Returns the Content-Encoding header for this coding.
+    pub fn header(&self) -> ContentEncoding {
+        match *self {
+            ContentCoding::Brotli => ContentEncoding(vec![Encoding::EncodingExt("br".into())]),
+            ContentCoding::Gzip => ContentEncoding(vec![Encoding::Gzip]),
+        }
+    }
+}
+
+/// Chooses which coding to compress a response with, following the Accept-Encoding header of
+/// the request. Brotli is preferred when both are equally acceptable.
+pub fn preferred_coding(req: &Request) -> Option<ContentCoding> {
+    let accepted = match req.headers.get::<AcceptEncoding>() {
+        Some(&AcceptEncoding(ref accepted)) => accepted,
+        None => return None,
+    };
+
+    let quality = |name: &str| {
+        let explicit = accepted.iter().find(|x| match x.item {
+            Encoding::Gzip => name == "gzip",
+            Encoding::EncodingExt(ref ext) => ext == name,
+            _ => false,
+        });
+
+        let wildcard = accepted.iter().find(|x| match x.item {
+            Encoding::EncodingExt(ref ext) => ext == "*",
+            _ => false,
+        });
+
+        explicit.or(wildcard).map(|x| x.quality.0).unwrap_or(0)
+    };
+
+    let brotli = quality("br");
+    let gzip = quality("gzip");
+
+    if brotli > 0 && brotli >= gzip {
+        Some(ContentCoding::Brotli)
+    } else if gzip > 0 {
+        Some(ContentCoding::Gzip)
+    } else {
+        None
+    }
+}
+
+/// Compresses some data with the specified coding.
+fn compress(data: &[u8], coding: ContentCoding) -> io::Result<Vec<u8>> {
+    let mut output = Vec::new();
+
+    match coding {
+        ContentCoding::Brotli => {
+            let mut encoder = brotli::CompressorWriter::new(&mut output, 4096, BROTLI_QUALITY, 22);
+            encoder.write_all(data)?;
+            // Finishes the stream
+            encoder.into_inner();
+        }
+        ContentCoding::Gzip => {
+            let mut encoder = GzEncoder::new(&mut output, Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()?;
+        }
+    }
+
+    Ok(output)
+}
+
+/// Marks the content of a response as dynamic and compressible. Responses without this, such
+/// as uploaded files, are sent as they are.
+pub struct Compressible;
+
+impl Key for Compressible {
+    type Value = ();
+}
+
+impl Modifier<Response> for Compressible {
+    fn modify(self, res: &mut Response) {
+        res.extensions.insert::<Compressible>(());
+    }
+}
+
+/// Marks a response as depending on the Accept-Encoding header of the request, so that caches
+/// don't send compressed responses to clients which didn't ask for them.
+pub fn set_vary(response: &mut Response) {
+    response
+        .headers
+        .set_raw("Vary", vec![b"Accept-Encoding".to_vec()]);
+}
+
+/// Middleware which compresses dynamic responses once they have been rendered.
+pub struct CompressionMiddleware {
+    /// Responses smaller than this (in bytes) aren't worth compressing.
+    pub threshold: u64,
+}
+
+impl AfterMiddleware for CompressionMiddleware {
+    fn after(&self, req: &mut Request, mut res: Response) -> IronResult<Response> {
+        if res.extensions.get::<Compressible>().is_none() || res.headers.has::<ContentEncoding>() {
+            return Ok(res);
+        }
+
+        set_vary(&mut res);
+
+        let coding = match preferred_coding(req) {
+            Some(coding) => coding,
+            None => return Ok(res),
+        };
+
+        let mut body = Vec::new();
+        match res.body.take() {
+            Some(mut writer) => writer.write_body(&mut body).map_err(|x| {
+                IronError::new(
+                    x,
+                    (status::InternalServerError, "Failed to render response"),
+                )
+            })?,
+            None => return Ok(res),
+        }
+
+        if (body.len() as u64) >= self.threshold {
+            body = compress(&body, coding).map_err(|x| {
+                IronError::new(
+                    x,
+                    (status::InternalServerError, "Failed to compress response"),
+                )
+            })?;
+            res.headers.set(coding.header());
+        }
+
+        res.headers.set(ContentLength(body.len() as u64));
+        res.body = Some(Box::new(body));
+
+        Ok(res)
+    }
+}
@@ -8,12 +8,21 @@ use std::path::Path;
 use toml;
 
 use iron::typemap::Key;
+use iron::Url;
+
+use expiry::parse_expiry;
 
 /// API key used to programmatically upload files.
-#[derive(Deserialize)]
+#[derive(Deserialize, Clone)]
 pub struct APIKey {
     pub key: String,
     pub comment: Option<String>,
+    /// The largest single upload (in bytes) allowed with this key.
+    pub max_upload_size: Option<u64>,
+    /// The most bytes which can be stored with this key at any one time.
+    pub max_total_size: Option<u64>,
+    /// The most uploads which can be made with this key in a 24 hour period.
+    pub max_uploads_per_day: Option<u64>,
 }
 
 /// A single element of a user's credentials.
@@ -25,6 +34,40 @@ pub struct UserCredentials {
     pub password: String,
 }
 
+/// Cache-Control header values for each class of content which is served.
+#[derive(Deserialize, Clone, Default)]
+pub struct CacheControlConfig {
+    /// Uploaded files. Defaults to "public, max-age=3600".
+    pub uploads: Option<String>,
+    /// Static files embedded in the executable. Defaults to "public, max-age=86400".
+    pub assets: Option<String>,
+    /// Rendered pages, such as text uploads and galleries. Defaults to "no-cache".
+    pub pages: Option<String>,
+}
+
+/// How uploads which browsers could run scripts from, such as HTML and SVG, are served.
+#[derive(Deserialize, Clone, Copy, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ActiveContentPolicy {
+    /// Sent as a download, so that they are never shown by the browser.
+    #[default]
+    Attachment,
+    /// Shown as plain text.
+    Text,
+}
+
+/// Controls how uploads are served, so that they can't attack the management UI.
+#[derive(Deserialize, Clone, Default)]
+pub struct ContentSafetyConfig {
+    /// How HTML, SVG and XML uploads are served. Either "attachment" (the default) or "text".
+    #[serde(default)]
+    pub active_content: ActiveContentPolicy,
+    /// A separate origin which the contents of uploads are served from, such as
+    /// "https://usercontent.example.com/". This should point at the same server as
+    /// external_url, but on another host.
+    pub user_content_url: Option<String>,
+}
+
 /// The config file contains configurable runtime properties, as well as user credentials.
 #[derive(Deserialize)]
 pub struct Config {
@@ -34,6 +77,27 @@ pub struct Config {
     pub api_keys: Vec<APIKey>,
     pub users: Vec<UserCredentials>,
     pub key: String,
+    /// How long uploads are kept for when they don't specify an expiry (e.g. "7d").
+    /// If this is not specified, uploads are kept forever by default.
+    pub default_expiry: Option<String>,
+    /// How often (in seconds) expired uploads are removed from disk. Defaults to 5 minutes.
+    pub reaper_interval: Option<u64>,
+    /// The smallest size (in bytes) at which dynamic responses are compressed. Defaults to 1KiB.
+    pub compression_threshold: Option<u64>,
+    /// The largest size (in bytes) of the cache of resized and converted images. Defaults to
+    /// 256MiB.
+    pub derivative_cache_size: Option<u64>,
+    /// The widths and heights which images can be resized to. Requested sizes are rounded up
+    /// to the nearest of these. Defaults to powers of two from 64 to 4096.
+    pub derivative_sizes: Option<Vec<u32>>,
+    /// The most images which can be resized or converted at once. Defaults to 2.
+    pub derivative_workers: Option<usize>,
+    /// How long clients and caches may keep each class of content for.
+    #[serde(default)]
+    pub cache_control: CacheControlConfig,
+    /// How uploads are protected from running scripts against visitors.
+    #[serde(default)]
+    pub content_safety: ContentSafetyConfig,
 }
 
 impl Config {
@@ -51,10 +115,38 @@ impl Config {
             Err(err) => return Err(err.description().to_string()),
         }
 
-        match toml::from_str(&config_contents) {
-            Ok(config) => Ok(config),
-            Err(serde_error) => Err(serde_error.description().to_string()),
+        let config: Config = match toml::from_str(&config_contents) {
+            Ok(config) => config,
+            Err(serde_error) => return Err(serde_error.description().to_string()),
+        };
+
+        match config.default_expiry {
+            Some(ref expiry) => {
+                parse_expiry(expiry)?;
+            }
+            None => (),
+        }
+
+        match config.derivative_sizes {
+            Some(ref sizes) if sizes.is_empty() || sizes.contains(&0) => {
+                return Err("derivative_sizes must contain sizes larger than 0".to_string());
+            }
+            _ => (),
         }
+
+        match config.content_safety.user_content_url {
+            Some(ref url) => {
+                // Sharing a host would give uploads the same origin as the management UI
+                if Url::parse(url)?.host() == Url::parse(&config.external_url)?.host() {
+                    return Err(
+                        "user_content_url must be on a different host to external_url".to_string(),
+                    );
+                }
+            }
+            None => (),
+        }
+
+        Ok(config)
     }
 }
 
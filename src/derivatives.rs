@@ -0,0 +1,532 @@
+//! Produces resized and re-encoded variants of image uploads, keeping a bounded cache of them
+//! on disk so that each variant is only produced once.
+//!
+//! Producing a variant is expensive, so requested sizes are rounded to a few configured ones,
+//! and only a limited number of variants are produced at once.
+
+use std::collections::HashSet;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::BufWriter;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Condvar;
+use std::sync::Mutex;
+
+use iron::mime::{Mime, SubLevel, TopLevel};
+use iron::prelude::*;
+use iron::status;
+use iron::typemap::Key;
+
+use filetime;
+use filetime::FileTime;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::imageops::FilterType;
+use image::DynamicImage;
+use image::GenericImageView;
+use image::ImageError;
+use image::ImageFormat;
+use image::ImageReader;
+use image::Limits;
+
+use params::Map;
+use params::Params;
+use params::Value;
+
+use rand;
+use rand::Rng;
+
+use types::FileMetadata;
+use types::StringError;
+
+/// The largest width or height which images can be resized to.
+const MAX_DIMENSION: u32 = 4096;
+
+/// The largest size (in bytes) of the cache when none is configured.
+pub const DEFAULT_CACHE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// The JPEG quality used when none is requested.
+const DEFAULT_QUALITY: u8 = 85;
+
+/// The sizes which requested widths and heights are rounded up to when none are configured.
+pub const DEFAULT_SIZES: &[u32] = &[64, 128, 256, 512, 1024, 2048, 4096];
+
+/// How many variants can be produced at once when this isn't configured.
+pub const DEFAULT_WORKERS: usize = 2;
+
+/// The largest width or height of an image which variants can be produced from.
+const MAX_SOURCE_DIMENSION: u32 = 16384;
+
+/// The most memory (in bytes) which decoding an image can use.
+const MAX_DECODE_ALLOCATION: u64 = 256 * 1024 * 1024;
+
+/// The formats which images can be converted to.
+#[derive(Copy, Clone, PartialEq)]
+pub enum OutputFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+}
+
+impl OutputFormat {
+    /// Parses a format as named in a query, e.g. "webp".
+    fn from_name(name: &str) -> Option<OutputFormat> {
+        match name.to_lowercase().as_ref() {
+            "png" => Some(OutputFormat::Png),
+            "jpeg" | "jpg" => Some(OutputFormat::Jpeg),
+            "gif" => Some(OutputFormat::Gif),
+            "webp" => Some(OutputFormat::Webp),
+            _ => None,
+        }
+    }
+
+    /// Finds the format of an existing image, if it can be encoded again.
+    fn from_mime(mime: &Mime) -> Option<OutputFormat> {
+        match *mime {
+            Mime(TopLevel::Image, SubLevel::Png, _) => Some(OutputFormat::Png),
+            Mime(TopLevel::Image, SubLevel::Jpeg, _) => Some(OutputFormat::Jpeg),
+            Mime(TopLevel::Image, SubLevel::Gif, _) => Some(OutputFormat::Gif),
+            Mime(TopLevel::Image, SubLevel::Ext(ref sub), _) if sub == "webp" => {
+                Some(OutputFormat::Webp)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the extension used for files of this format.
+    pub fn extension(&self) -> &'static str {
+        match *self {
+            OutputFormat::Png => "png",
+            OutputFormat::Jpeg => "jpg",
+            OutputFormat::Gif => "gif",
+            OutputFormat::Webp => "webp",
+        }
+    }
+
+    /// Returns the content type of this format.
+    pub fn mime(&self) -> Mime {
+        let sub = match *self {
+            OutputFormat::Png => SubLevel::Png,
+            OutputFormat::Jpeg => SubLevel::Jpeg,
+            OutputFormat::Gif => SubLevel::Gif,
+            OutputFormat::Webp => SubLevel::Ext("webp".into()),
+        };
+
+        Mime(TopLevel::Image, sub, Vec::new())
+    }
+}
+
+/// Checks if an upload is an image which can be resized or converted.
+pub fn is_transformable(mime: &Mime) -> bool {
+    match *mime {
+        Mime(TopLevel::Image, SubLevel::Ext(ref sub), _) if sub == "bmp" => true,
+        ref mime => OutputFormat::from_mime(mime).is_some(),
+    }
+}
+
+/// A resized or converted variant of an image, as requested with the w, h, fmt and q query
+/// parameters.
+pub struct Transform {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub format: Option<OutputFormat>,
+    pub quality: Option<u8>,
+}
+
+/// Reads an optional numeric query parameter, which must be within the specified bounds.
+fn bounded_param(map: &Map, key: &str, min: u32, max: u32) -> IronResult<Option<u32>> {
+    let value = match map.get(key) {
+        Some(&Value::String(ref value)) => value.trim().parse::<u32>().ok(),
+        Some(&Value::U64(value)) => Some(value as u32),
+        Some(_) => None,
+        None => return Ok(None),
+    };
+
+    match value {
+        Some(value) if value >= min && value <= max => Ok(Some(value)),
+        _ => Err(IronError::new(
+            StringError(format!("{} must be between {} and {}", key, min, max)),
+            (status::BadRequest, "Invalid image parameters"),
+        )),
+    }
+}
+
+/// Rounds a requested width or height up to the nearest of the allowed sizes, so that only a
+/// few variants of each image can be produced. Sizes past the largest allowed are reduced to it.
+fn snap_size(size: u32, sizes: &[u32]) -> u32 {
+    let larger = sizes.iter().filter(|x| **x >= size).min();
+    let largest = sizes.iter().max();
+
+    match larger.or(largest) {
+        Some(snapped) => *snapped,
+        None => size,
+    }
+}
+
+impl Transform {
+    /// Reads the transform requested by the query of a request, if any. Widths and heights are
+    /// rounded to the nearest of the specified sizes.
+    pub fn from_request(req: &mut Request, sizes: &[u32]) -> IronResult<Option<Transform>> {
+        let map = req.get_ref::<Params>().expect("No Params object available");
+
+        let format = match map.get("fmt") {
+            Some(&Value::String(ref name)) => match OutputFormat::from_name(name) {
+                Some(format) => Some(format),
+                None => {
+                    return Err(IronError::new(
+                        StringError(format!("Unknown image format {:?}", name)),
+                        (status::BadRequest, "Invalid image parameters"),
+                    ))
+                }
+            },
+            _ => None,
+        };
+
+        let transform = Transform {
+            width: bounded_param(map, "w", 1, MAX_DIMENSION)?.map(|x| snap_size(x, sizes)),
+            height: bounded_param(map, "h", 1, MAX_DIMENSION)?.map(|x| snap_size(x, sizes)),
+            format,
+            quality: bounded_param(map, "q", 1, 100)?.map(|x| x as u8),
+        };
+
+        if transform.width.is_none()
+            && transform.height.is_none()
+            && transform.format.is_none()
+            && transform.quality.is_none()
+        {
+            Ok(None)
+        } else {
+            Ok(Some(transform))
+        }
+    }
+
+    /// Identifies the variant produced by this transform, when encoded in the specified format.
+    /// Only JPEG images have a quality, so it doesn't distinguish variants in other formats.
+    pub fn key(&self, format: OutputFormat) -> String {
+        let dimension = |x: Option<u32>| x.map(|x| x.to_string()).unwrap_or_default();
+        let size = format!("{}x{}", dimension(self.width), dimension(self.height));
+
+        match format {
+            OutputFormat::Jpeg => format!(
+                "{}-q{}.{}",
+                size,
+                self.quality.unwrap_or(DEFAULT_QUALITY),
+                format.extension()
+            ),
+            _ => format!("{}.{}", size, format.extension()),
+        }
+    }
+
+    /// Resizes an image to fit within the requested dimensions, keeping its aspect ratio.
+    /// Images are never enlarged.
+    fn resize(&self, image: DynamicImage) -> DynamicImage {
+        let (width, height) = image.dimensions();
+        let target_width = self.width.unwrap_or(width).min(width);
+        let target_height = self.height.unwrap_or(height).min(height);
+
+        if target_width == width && target_height == height {
+            image
+        } else {
+            image.resize(target_width, target_height, FilterType::Lanczos3)
+        }
+    }
+}
+
+/// Limits how many variants are produced at once, and makes sure that concurrent requests for
+/// the same variant only produce it once.
+pub struct Generator {
+    state: Mutex<GeneratorState>,
+    changed: Condvar,
+    workers: usize,
+}
+
+/// The variants which are currently being produced.
+struct GeneratorState {
+    in_progress: HashSet<PathBuf>,
+}
+
+/// Contains the generator used by all requests.
+#[derive(Copy, Clone)]
+pub struct GeneratorContainer;
+
+impl Key for GeneratorContainer {
+    type Value = Generator;
+}
+
+/// Held while a variant is being produced, allowing others to be produced once dropped.
+struct Slot<'a> {
+    generator: &'a Generator,
+    path: PathBuf,
+}
+
+impl Generator {
+    /// Creates a generator which produces up to the specified number of variants at once.
+    pub fn new(workers: usize) -> Generator {
+        Generator {
+            state: Mutex::new(GeneratorState {
+                in_progress: HashSet::new(),
+            }),
+            changed: Condvar::new(),
+            workers: workers.max(1),
+        }
+    }
+
+    /// Waits until the variant at the specified path can be produced, i.e. until there is a
+    /// free slot and no other request is producing the same variant.
+    fn acquire<'a>(&'a self, path: &Path) -> Slot<'a> {
+        let mut state = self.state.lock().unwrap_or_else(|x| x.into_inner());
+
+        while state.in_progress.len() >= self.workers || state.in_progress.contains(path) {
+            state = self.changed.wait(state).unwrap_or_else(|x| x.into_inner());
+        }
+
+        state.in_progress.insert(path.to_owned());
+
+        Slot {
+            generator: self,
+            path: path.to_owned(),
+        }
+    }
+}
+
+impl<'a> Drop for Slot<'a> {
+    fn drop(&mut self) {
+        let mut state = self
+            .generator
+            .state
+            .lock()
+            .unwrap_or_else(|x| x.into_inner());
+        state.in_progress.remove(&self.path);
+
+        // Requests may be waiting for either a free slot or this variant
+        self.generator.changed.notify_all();
+    }
+}
+
+/// A variant of an image, stored within the cache.
+pub struct Derivative {
+    pub path: PathBuf,
+    pub format: OutputFormat,
+    pub key: String,
+}
+
+/// Converts an error from the image library into an I/O error.
+fn image_error(error: ImageError) -> io::Error {
+    match error {
+        ImageError::IoError(error) => error,
+        error => io::Error::new(ErrorKind::InvalidData, error.to_string()),
+    }
+}
+
+/// Encodes an image in the specified format to a file.
+fn encode(image: &DynamicImage, format: OutputFormat, quality: u8, path: &Path) -> io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+
+    let result = match format {
+        // JPEG and WebP images can't be encoded from every color type, so are converted first
+        OutputFormat::Jpeg => {
+            JpegEncoder::new_with_quality(&mut writer, quality).encode_image(&image.to_rgb8())
+        }
+        OutputFormat::Webp => {
+            DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut writer, ImageFormat::WebP)
+        }
+        OutputFormat::Gif => {
+            DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut writer, ImageFormat::Gif)
+        }
+        OutputFormat::Png => image.write_to(&mut writer, ImageFormat::Png),
+    };
+
+    result.map_err(image_error)
+}
+
+/// Removes the least recently used variants from the cache, until it is no larger than the
+/// specified size in bytes.
+fn evict(dir: &Path, max_size: u64) -> io::Result<()> {
+    let mut entries = Vec::new();
+    let mut total = 0;
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+
+        // Variants which are still being written are left alone
+        if !metadata.is_file() || entry.path().extension().map_or(false, |x| x == "tmp") {
+            continue;
+        }
+
+        total += metadata.len();
+        entries.push((metadata.modified()?, entry.path(), metadata.len()));
+    }
+
+    entries.sort_by_key(|x| x.0);
+
+    for (_, path, length) in entries {
+        if total <= max_size {
+            break;
+        }
+
+        match fs::remove_file(&path) {
+            Err(ref e) if e.kind() != ErrorKind::NotFound => {
+                eprintln!("Failed to remove cached image {:?}: {}", path, e)
+            }
+            _ => total -= length,
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the cached variants of an upload, so that they can't be served once it is gone.
+pub fn remove_derivatives(base_path: &str, name: &str) -> io::Result<()> {
+    let entries = match fs::read_dir(Path::new(base_path).join("derivatives")) {
+        Ok(entries) => entries,
+        Err(ref e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    // Names can't contain dots, so this can't match the variants of another upload
+    let prefix = format!("{}.", name);
+
+    for entry in entries {
+        let entry = entry?;
+
+        if !entry.file_name().to_string_lossy().starts_with(&prefix) {
+            continue;
+        }
+
+        match fs::remove_file(entry.path()) {
+            Err(ref e) if e.kind() == ErrorKind::NotFound => (),
+            result => result?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Marks a cached variant as recently used, so that it is evicted last.
+fn touch(path: &Path) {
+    match filetime::set_file_mtime(path, FileTime::now()) {
+        Err(e) => eprintln!("Failed to touch cached image {:?}: {}", path, e),
+        _ => (),
+    }
+}
+
+/// Decodes an image, refusing images which would use too much memory.
+fn decode(path: &Path) -> io::Result<DynamicImage> {
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SOURCE_DIMENSION);
+    limits.max_image_height = Some(MAX_SOURCE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_ALLOCATION);
+
+    let mut reader = ImageReader::open(path)?.with_guessed_format()?;
+    reader.limits(limits);
+    reader.decode().map_err(image_error)
+}
+
+/// Finds the variant of an uploaded image produced by a transform, producing and caching it
+/// if it hasn't been already.
+pub fn derivative(
+    base_path: &str,
+    name: &str,
+    meta: &FileMetadata,
+    transform: &Transform,
+    generator: &Generator,
+    max_cache_size: u64,
+) -> io::Result<Derivative> {
+    let source = Path::new(base_path).join(meta.actual_filename.clone().unwrap_or_default());
+
+    // Formats which can't be encoded again, such as BMP, are converted to PNG
+    let format = transform
+        .format
+        .or_else(|| OutputFormat::from_mime(&meta.content_type()))
+        .unwrap_or(OutputFormat::Png);
+
+    // The hash of the contents is included, so that variants of a replaced upload aren't used
+    let key = transform.key(format);
+    let hash = meta.hash.clone().unwrap_or_default();
+    let dir = Path::new(base_path).join("derivatives");
+    let path = dir.join(format!("{}.{:.16}.{}", name, hash, key));
+
+    if path.exists() {
+        touch(&path);
+        return Ok(Derivative { path, format, key });
+    }
+
+    let _slot = generator.acquire(&path);
+
+    // Another request may have produced the variant while this one was waiting
+    if path.exists() {
+        touch(&path);
+        return Ok(Derivative { path, format, key });
+    }
+
+    fs::create_dir_all(&dir)?;
+
+    let image = transform.resize(decode(&source)?);
+
+    // Written to a temporary file first, so that partially written variants are never used
+    let temp = dir.join(format!("{}.{}.tmp", name, rand::thread_rng().gen::<u64>()));
+
+    let result = encode(
+        &image,
+        format,
+        transform.quality.unwrap_or(DEFAULT_QUALITY),
+        &temp,
+    )
+    .and_then(|_| fs::rename(&temp, &path));
+
+    match result {
+        Err(e) => {
+            let _ = fs::remove_file(&temp);
+            return Err(e);
+        }
+        Ok(_) => (),
+    }
+
+    match evict(&dir, max_cache_size) {
+        Err(e) => eprintln!("Failed to evict cached images: {}", e),
+        _ => (),
+    }
+
+    Ok(Derivative { path, format, key })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_are_rounded_up() {
+        assert_eq!(snap_size(1, DEFAULT_SIZES), 64);
+        assert_eq!(snap_size(64, DEFAULT_SIZES), 64);
+        assert_eq!(snap_size(65, DEFAULT_SIZES), 128);
+        assert_eq!(snap_size(1000, DEFAULT_SIZES), 1024);
+    }
+
+    #[test]
+    fn sizes_are_capped_at_largest() {
+        assert_eq!(snap_size(4000, &[100, 500]), 500);
+    }
+
+    #[test]
+    fn quality_only_distinguishes_jpeg_variants() {
+        let transform = |quality| Transform {
+            width: Some(256),
+            height: None,
+            format: None,
+            quality,
+        };
+
+        assert_eq!(transform(None).key(OutputFormat::Jpeg), "256x-q85.jpg");
+        assert_eq!(transform(Some(50)).key(OutputFormat::Jpeg), "256x-q50.jpg");
+        assert_eq!(transform(Some(50)).key(OutputFormat::Png), "256x.png");
+        assert_eq!(
+            transform(None).key(OutputFormat::Webp),
+            transform(Some(50)).key(OutputFormat::Webp)
+        );
+    }
+}
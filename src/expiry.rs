@@ -0,0 +1,87 @@
+//! Contains helpers for the expiry of uploads, as well as the background reaper which
+//! removes them once they have expired.
+
+use types::FileMetadata;
+
+use std::thread;
+use std::time;
+
+use chrono::Duration;
+
+/// The longest an upload can be set to live for (roughly 100 years).
+const MAX_EXPIRY_SECONDS: i64 = 100 * 365 * 24 * 60 * 60;
+
+/// Parses an expiry specification, such as "30m", "12h", "7d" or "2w". A bare number is
+/// treated as a number of seconds. "never" explicitly requests that an upload doesn't expire,
+/// and is returned as None.
+pub fn parse_expiry(input: &str) -> Result<Option<Duration>, String> {
+    let input = input.trim();
+
+    if input == "never" {
+        return Ok(None);
+    }
+
+    let unit = match input.chars().last() {
+        Some(unit) => unit,
+        None => return Err("No expiry specified".into()),
+    };
+
+    let (amount, multiplier) = match unit {
+        's' => (&input[..input.len() - 1], 1),
+        'm' => (&input[..input.len() - 1], 60),
+        'h' => (&input[..input.len() - 1], 60 * 60),
+        'd' => (&input[..input.len() - 1], 24 * 60 * 60),
+        'w' => (&input[..input.len() - 1], 7 * 24 * 60 * 60),
+        _ => (input, 1),
+    };
+
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| format!("Expiry {:?} isn't a valid duration", input))?;
+
+    match amount.checked_mul(multiplier) {
+        Some(seconds) if seconds > 0 && seconds <= MAX_EXPIRY_SECONDS => {
+            Ok(Some(Duration::seconds(seconds)))
+        }
+        _ => Err(format!("Expiry {:?} is out of range", input)),
+    }
+}
+
+/// Removes all expired uploads within the specified root.
+fn reap_expired(root: &str) {
+    let names = match FileMetadata::list_names(root) {
+        Ok(names) => names,
+        Err(e) => {
+            eprintln!("Unable to list uploads for expiry: {}", e);
+            return;
+        }
+    };
+
+    for name in names {
+        match FileMetadata::from_path(root, &name) {
+            Ok(meta) => {
+                if meta.is_expired() {
+                    println!("Removing expired file {:?}...", name);
+
+                    match meta.remove(root, &name) {
+                        Err(e) => eprintln!("Failed to remove expired file {:?}: {}", name, e),
+                        _ => {}
+                    }
+                }
+            }
+            Err(e) => eprintln!("Failed to open file {:?}: {}", name, e),
+        }
+    }
+}
+
+/// Starts a background thread which periodically removes expired uploads from the
+/// specified root.
+pub fn spawn_reaper(root: String, interval: time::Duration) {
+    thread::Builder::new()
+        .name("reaper".into())
+        .spawn(move || loop {
+            reap_expired(&root);
+            thread::sleep(interval);
+        })
+        .expect("Unable to start expiry reaper");
+}
@@ -0,0 +1,112 @@
+//! Answers HEAD requests with the headers of the equivalent GET request, but no body.
+//!
+//! The router falls back to GET handlers for HEAD requests, rewriting the method as it does
+//! so, and nothing below it omits the body. HEAD requests are therefore marked before routing,
+//! so that handlers can avoid side effects, and their bodies are dropped once rendered.
+
+use std::io;
+
+use iron::headers::ContentLength;
+use iron::method;
+use iron::prelude::*;
+use iron::status;
+use iron::typemap::Key;
+use iron::AfterMiddleware;
+use iron::BeforeMiddleware;
+
+use types::StringError;
+
+/// Marks a request which was originally made with HEAD.
+struct HeadRequest;
+
+impl Key for HeadRequest {
+    type Value = ();
+}
+
+/// Checks if a request was made with HEAD, even after the router has treated it as GET.
+pub fn is_head(req: &Request) -> bool {
+    req.extensions.contains::<HeadRequest>()
+}
+
+/// Removes the body of a response, keeping its Content-Length. Bodies without a known length
+/// are rendered to find one.
+fn strip_body(res: &mut Response) -> io::Result<()> {
+    let body = match res.body.take() {
+        Some(body) => body,
+        None => return Ok(()),
+    };
+
+    if !res.headers.has::<ContentLength>() {
+        let mut body = body;
+        let mut rendered = Vec::new();
+        body.write_body(&mut rendered)?;
+        res.headers.set(ContentLength(rendered.len() as u64));
+    }
+
+    // An empty body stops Iron from replacing the Content-Length with zero
+    res.body = Some(Box::new(Vec::new()));
+
+    Ok(())
+}
+
+/// Middleware which marks HEAD requests, and removes the bodies of their responses. This
+/// should be linked last, so that the length of the final body is sent.
+pub struct HeadMiddleware;
+
+impl BeforeMiddleware for HeadMiddleware {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        if req.method == method::Head {
+            req.extensions.insert::<HeadRequest>(());
+        }
+
+        Ok(())
+    }
+}
+
+impl AfterMiddleware for HeadMiddleware {
+    fn after(&self, req: &mut Request, mut res: Response) -> IronResult<Response> {
+        if is_head(req) {
+            strip_body(&mut res).map_err(|x| {
+                IronError::new(
+                    x,
+                    (status::InternalServerError, "Failed to render response"),
+                )
+            })?;
+        }
+
+        Ok(res)
+    }
+
+    fn catch(&self, req: &mut Request, mut err: IronError) -> IronResult<Response> {
+        if is_head(req) {
+            match strip_body(&mut err.response) {
+                Err(e) => eprintln!("Failed to render error response: {}", e),
+                _ => (),
+            }
+        }
+
+        Err(err)
+    }
+}
+
+/// Middleware which refuses HEAD requests for endpoints that change state when fetched, such
+/// as deletion links, which the router would otherwise run in place of a HEAD handler.
+pub struct RejectHead {
+    /// Paths which are refused if they start with any of these. No leading slash.
+    pub prefixes: Vec<String>,
+}
+
+impl BeforeMiddleware for RejectHead {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let path = req.url.path().join("/");
+
+        if is_head(req) && self.prefixes.iter().any(|x| path.starts_with(x)) {
+            return Err(IronError::new(
+                StringError(format!("HEAD request for {:?}", path)),
+                (status::MethodNotAllowed, "HEAD isn't supported here"),
+            ));
+        }
+
+        Ok(())
+    }
+}
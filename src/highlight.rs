@@ -0,0 +1,172 @@
+//! Highlights the syntax of text pastes when they are rendered, so that they can be read
+//! without JavaScript.
+
+use handlebars::html_escape;
+
+use iron::typemap::Key;
+
+use syntect::easy::HighlightLines;
+use syntect::highlighting::Theme;
+use syntect::highlighting::ThemeSet;
+use syntect::html::styled_line_to_highlighted_html;
+use syntect::html::IncludeBackground;
+use syntect::parsing::SyntaxReference;
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+/// The theme used for highlighting, which matches the dark background of the text view.
+const THEME: &str = "base16-eighties.dark";
+
+/// Pastes larger than this (in bytes) are shown without highlighting, as it would take too
+/// long to render them.
+const MAX_HIGHLIGHT_SIZE: usize = 256 * 1024;
+
+/// A single line of a highlighted paste.
+#[derive(Serialize)]
+pub struct Line {
+    pub number: usize,
+    pub html: String,
+    pub selected: bool,
+}
+
+/// The syntax definitions and theme used for highlighting. These are loaded once on startup.
+pub struct Highlighter {
+    syntaxes: SyntaxSet,
+    theme: Theme,
+}
+
+/// Contains the highlighter used by all requests.
+#[derive(Copy, Clone)]
+pub struct HighlighterContainer;
+
+impl Key for HighlighterContainer {
+    type Value = Highlighter;
+}
+
+/// Parses a range of lines, such as "10-20" or "10", returning the first and last line.
+pub fn parse_line_range(range: &str) -> Option<(usize, usize)> {
+    let mut parts = range.trim().splitn(2, '-');
+
+    let first = parts.next()?.trim().trim_start_matches('L').parse().ok()?;
+    let last = match parts.next() {
+        Some(last) => last.trim().trim_start_matches('L').parse().ok()?,
+        None => first,
+    };
+
+    if first > 0 && first <= last {
+        Some((first, last))
+    } else {
+        None
+    }
+}
+
+impl Highlighter {
+    /// Loads the syntax definitions and theme bundled with the executable.
+    pub fn new() -> Highlighter {
+        let mut themes = ThemeSet::load_defaults();
+
+        Highlighter {
+            syntaxes: SyntaxSet::load_defaults_newlines(),
+            theme: themes
+                .themes
+                .remove(THEME)
+                .expect("Highlighting theme isn't bundled"),
+        }
+    }
+
+    /// Finds the syntax for some contents, from either the name or extension of a language,
+    /// or failing that, the first line of the contents (e.g. a shebang).
+    fn find_syntax(&self, language: Option<&str>, contents: &str) -> &SyntaxReference {
+        language
+            .and_then(|language| self.syntaxes.find_syntax_by_token(language))
+            .or_else(|| self.syntaxes.find_syntax_by_first_line(contents))
+            .unwrap_or_else(|| self.syntaxes.find_syntax_plain_text())
+    }
+
+    /// Highlights some contents, returning the HTML for each of its lines.
+    fn highlight(&self, contents: &str, language: Option<&str>) -> Vec<String> {
+        if contents.len() > MAX_HIGHLIGHT_SIZE {
+            return contents.lines().map(html_escape).collect();
+        }
+
+        let mut highlighter =
+            HighlightLines::new(self.find_syntax(language, contents), &self.theme);
+
+        LinesWithEndings::from(contents)
+            .map(|line| {
+                let html = highlighter
+                    .highlight_line(line, &self.syntaxes)
+                    .and_then(|regions| {
+                        styled_line_to_highlighted_html(&regions, IncludeBackground::No)
+                    });
+
+                // Lines are laid out by the template, so don't need their own line breaks
+                match html {
+                    Ok(html) => html.replace("\n", "").replace("\r", ""),
+                    Err(_) => html_escape(line.trim_end_matches(|x| x == '\n' || x == '\r')),
+                }
+            })
+            .collect()
+    }
+
+    /// Highlights some contents as numbered lines, marking the lines within the selected range.
+    pub fn highlight_lines(
+        &self,
+        contents: &str,
+        language: Option<&str>,
+        selected: Option<(usize, usize)>,
+    ) -> Vec<Line> {
+        self.highlight(contents, language)
+            .into_iter()
+            .enumerate()
+            .map(|(index, html)| Line {
+                number: index + 1,
+                html,
+                selected: match selected {
+                    Some((first, last)) => (first..=last).contains(&(index + 1)),
+                    None => false,
+                },
+            })
+            .collect()
+    }
+
+    /// Highlights a block of code, such as one within a markdown document.
+    pub fn highlight_block(&self, contents: &str, language: Option<&str>) -> String {
+        format!(
+            "<pre class=\"highlight\"><code>{}</code></pre>",
+            self.highlight(contents, language).join("\n")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_lines_are_parsed() {
+        assert_eq!(parse_line_range("10"), Some((10, 10)));
+        assert_eq!(parse_line_range("L10"), Some((10, 10)));
+        assert_eq!(parse_line_range(" 3 "), Some((3, 3)));
+    }
+
+    #[test]
+    fn ranges_are_parsed() {
+        assert_eq!(parse_line_range("10-20"), Some((10, 20)));
+        assert_eq!(parse_line_range("L10-L20"), Some((10, 20)));
+        assert_eq!(parse_line_range("5-5"), Some((5, 5)));
+        assert_eq!(parse_line_range("1 - 2"), Some((1, 2)));
+    }
+
+    #[test]
+    fn invalid_ranges_are_rejected() {
+        assert_eq!(parse_line_range(""), None);
+        assert_eq!(parse_line_range("0"), None);
+        assert_eq!(parse_line_range("0-5"), None);
+        assert_eq!(parse_line_range("20-10"), None);
+        assert_eq!(parse_line_range("-5"), None);
+        assert_eq!(parse_line_range("5-"), None);
+        assert_eq!(parse_line_range("a-b"), None);
+        assert_eq!(parse_line_range("1-2-3"), None);
+    }
+}
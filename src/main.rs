@@ -1,9 +1,8 @@
 //! The main entrypoint for the application. Contains the main function for initialisation
 //! of the webserver.
 #![forbid(unsafe_code)]
-#![feature(plugin)]
-#![plugin(phf_macros)]
 
+extern crate bodyparser;
 extern crate handlebars;
 extern crate handlebars_iron;
 extern crate iron;
@@ -22,19 +21,42 @@ extern crate base64;
 extern crate rand;
 extern crate tiny_keccak;
 
+#[macro_use]
 extern crate phf;
 
 extern crate chrono;
+extern crate filetime;
 
+extern crate image;
+extern crate infer;
 extern crate mime_guess;
+extern crate pulldown_cmark;
+extern crate qrcode;
+extern crate syntect;
+
+extern crate brotli;
+extern crate flate2;
 
 mod assets;
 mod auth;
+mod blobs;
+mod caching;
+mod compression;
 mod config;
+mod derivatives;
+mod expiry;
+mod head;
+mod highlight;
+mod markdown;
+mod ranges;
 mod rng;
 mod routes;
+mod safety;
+mod sniff;
 mod splitter;
+mod stats;
 mod types;
+mod usage;
 
 use auth::*;
 use rng::*;
@@ -43,21 +65,48 @@ use assets::get_file;
 use assets::list_files;
 use assets::TEMPLATES as templates;
 
+use compression::CompressionMiddleware;
+
 use config::Config;
 use config::ConfigContainer;
 
+use derivatives::Generator;
+use derivatives::GeneratorContainer;
+use derivatives::DEFAULT_WORKERS;
+
+use highlight::Highlighter;
+use highlight::HighlighterContainer;
+
+use expiry::spawn_reaper;
+
+use head::HeadMiddleware;
+use head::RejectHead;
+
 use routes::auth::login;
 use routes::auth::logout;
+use routes::files::download_file;
 use routes::files::get_pushed_file;
+use routes::files::get_raw_file;
+use routes::files::unlock_pushed_file;
+use routes::files::ViewLimitLock;
+use routes::files::ViewLocks;
 use routes::homepage::homepage;
 use routes::manage::listing;
 use routes::manage::manage;
 use routes::modify::delete_file;
+use routes::modify::delete_file_with_token;
 use routes::modify::rename_file;
+use routes::oembed::oembed;
+use routes::qr::qr_code;
 use routes::upload::upload;
 
+use safety::MainOriginOnly;
+
 use splitter::ChainSplit;
 
+use stats::spawn_recorder;
+use stats::StatsContainer;
+
 use iron::method;
 use iron::prelude::*;
 use iron::AroundMiddleware;
@@ -71,10 +120,13 @@ use handlebars::Handlebars;
 
 use handlebars_iron::HandlebarsEngine;
 
+use std::time::Duration;
+
 /// The main entrypoint for the application.
 fn main() {
     let config = Config::from_file("config.toml").expect("Unable to load configuration");
     let bind_addr = config.bind_addr.to_owned();
+    let compression_threshold = config.compression_threshold.unwrap_or(1024);
 
     // Generate the crypto-key used for sessions, sourced from the configuration key.
     let mut key = [0 as u8; 32];
@@ -125,6 +177,21 @@ fn main() {
         include_str!("../res/dictionary_nouns.txt"),
     );
 
+    // Load the syntax definitions used to highlight pastes
+    let highlighter = Highlighter::new();
+
+    // Limit how many resized images are produced at once
+    let generator = Generator::new(config.derivative_workers.unwrap_or(DEFAULT_WORKERS));
+
+    // Start removing expired files in the background
+    spawn_reaper(
+        config.base_path.to_owned(),
+        Duration::from_secs(config.reaper_interval.unwrap_or(300)),
+    );
+
+    // Start saving access stats in the background
+    let recorder = spawn_recorder(config.base_path.to_owned());
+
     // Build the primary router
 
     // Authenticated endpoints - this sets a cookie, which could normally have privacy concerns.
@@ -135,6 +202,12 @@ fn main() {
     router.route(method::Get, "/manage", manage, "manage");
     router.route(method::Get, "/listing", listing, "listing");
     router.route(method::Get, "/delete/:file", delete_file, "delete");
+    router.route(
+        method::Get,
+        "/delete/:file/:token",
+        delete_file_with_token,
+        "delete_token",
+    );
     router.route(
         method::Get,
         "/rename/:source/:target",
@@ -142,14 +215,35 @@ fn main() {
         "rename",
     );
     router.route(method::Post, "/upload/:type", upload, "upload");
+    router.route(method::Put, "/upload/:type/:filename", upload, "upload_raw");
 
     // Non-authenticated endpoints - no cookies here.
     let mut router_no_cookie = Router::new();
+    router_no_cookie.route(method::Get, "/oembed", oembed, "oembed");
+    router_no_cookie.route(method::Get, "/qr/:name", qr_code, "qr");
+    router_no_cookie.route(method::Get, "/raw/:name", get_raw_file, "raw");
+    router_no_cookie.route(method::Get, "/dl/:name", download_file, "download");
     router_no_cookie.route(method::Get, "/*", get_pushed_file, "generic_file_handler");
+    router_no_cookie.route(
+        method::Post,
+        "/*",
+        unlock_pushed_file,
+        "generic_file_unlock",
+    );
+
+    // The management UI is never served alongside the contents of uploads.
+    let mut authenticated = Chain::new(router);
+    authenticated.link_before(MainOriginOnly);
+    authenticated.link_before(RejectHead {
+        prefixes: vec!["delete/", "rename/", "logout"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect(),
+    });
 
     // Splitter delegates between authenticated and non-authenticated endpoints.
     let split = ChainSplit::new(
-        middleware.around(Box::new(router)),
+        middleware.around(Box::new(authenticated)),
         router_no_cookie,
         vec!["delete/", "upload/", "rename/"]
             .iter()
@@ -163,9 +257,18 @@ fn main() {
 
     // Final chain adds on general metadata.
     let mut chain = Chain::new(split);
+    chain.link_before(HeadMiddleware);
     chain.link(persistent::Read::<ConfigContainer>::both(config));
     chain.link(persistent::Read::<PhraseGeneratorContainer>::both(phrases));
+    chain.link(persistent::Read::<HighlighterContainer>::both(highlighter));
+    chain.link(persistent::Read::<StatsContainer>::both(recorder));
+    chain.link(persistent::Read::<ViewLimitLock>::both(ViewLocks::default()));
+    chain.link(persistent::Read::<GeneratorContainer>::both(generator));
     chain.link_after(hbse);
+    chain.link_after(CompressionMiddleware {
+        threshold: compression_threshold,
+    });
+    chain.link_after(HeadMiddleware);
 
     println!("Starting server on {:?}...", bind_addr);
 
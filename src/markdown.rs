@@ -0,0 +1,244 @@
+//! Renders markdown pastes as HTML, along with a table of contents for their headings.
+//!
+//! Pastes are written by anyone with an API key, but viewed by anyone with the link, so raw
+//! HTML within them is escaped rather than passed through, and only links with known safe
+//! schemes are kept.
+
+use highlight::Highlighter;
+
+use pulldown_cmark::html;
+use pulldown_cmark::CodeBlockKind;
+use pulldown_cmark::CowStr;
+use pulldown_cmark::Event;
+use pulldown_cmark::Options;
+use pulldown_cmark::Parser;
+use pulldown_cmark::Tag;
+
+/// Link schemes which can't be used to run scripts.
+const SAFE_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// A heading within a rendered document, as shown in its table of contents.
+#[derive(Serialize)]
+pub struct Heading {
+    pub level: usize,
+    pub id: String,
+    pub text: String,
+}
+
+/// A markdown document rendered as HTML.
+#[derive(Serialize)]
+pub struct Rendered {
+    pub html: String,
+    pub toc: Vec<Heading>,
+}
+
+/// Checks if a filename has an extension used for markdown.
+pub fn is_markdown_filename(filename: &str) -> bool {
+    let filename = filename.to_lowercase();
+    filename.ends_with(".md") || filename.ends_with(".markdown")
+}
+
+/// Checks if the destination of a link or image is either relative, or uses a safe scheme.
+fn is_safe_url(url: &str) -> bool {
+    let url = url.trim().to_lowercase();
+
+    // Anything before the first of these characters is a scheme
+    match url.find(|x| x == ':' || x == '/' || x == '?' || x == '#') {
+        Some(index) if url[index..].starts_with(':') => SAFE_SCHEMES.contains(&&url[..index]),
+        _ => true,
+    }
+}
+
+/// Builds an anchor for a heading from its text, which is unique within the document.
+fn heading_id(text: &str, used: &mut Vec<String>) -> String {
+    let mut slug = String::new();
+    for c in text.trim().to_lowercase().chars() {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if (c == ' ' || c == '-' || c == '_') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    let slug = match slug.trim_matches('-') {
+        "" => "section".to_string(),
+        slug => slug.to_string(),
+    };
+
+    let mut id = format!("md-{}", slug);
+    let mut suffix = 1;
+    while used.contains(&id) {
+        id = format!("md-{}-{}", slug, suffix);
+        suffix += 1;
+    }
+
+    used.push(id.clone());
+    id
+}
+
+/// Renders some CommonMark as sanitised HTML, with code blocks highlighted.
+pub fn render(source: &str, highlighter: &Highlighter) -> Rendered {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+    options.insert(Options::ENABLE_FOOTNOTES);
+
+    let events: Vec<Event> = Parser::new_ext(source, options).collect();
+
+    // Headings are found first, so that their anchors are known when they are rendered
+    let mut toc = Vec::new();
+    let mut used = Vec::new();
+    let mut current: Option<(usize, String)> = None;
+
+    for event in &events {
+        match *event {
+            Event::Start(Tag::Heading(level, _, _)) => {
+                current = Some((level as usize, String::new()));
+            }
+            Event::Text(ref text) | Event::Code(ref text) => match current {
+                Some((_, ref mut heading)) => heading.push_str(text),
+                None => (),
+            },
+            Event::End(Tag::Heading(_, _, _)) => match current.take() {
+                Some((level, text)) => toc.push(Heading {
+                    level,
+                    id: heading_id(&text, &mut used),
+                    text,
+                }),
+                None => (),
+            },
+            _ => (),
+        }
+    }
+
+    let mut headings = toc.iter();
+    let mut rendered = Vec::new();
+
+    // The language and contents of the code block currently being collected
+    let mut code_block: Option<(String, String)> = None;
+
+    for event in events {
+        match code_block.take() {
+            // Code blocks are highlighted as a whole, once all of their text is known
+            Some((language, mut code)) => match event {
+                Event::End(Tag::CodeBlock(_)) => {
+                    let language = match language.as_ref() {
+                        "" => None,
+                        language => Some(language),
+                    };
+
+                    rendered.push(Event::Html(CowStr::from(
+                        highlighter.highlight_block(&code, language),
+                    )));
+                }
+                event => {
+                    match event {
+                        Event::Text(text) => code.push_str(&text),
+                        _ => (),
+                    }
+
+                    code_block = Some((language, code));
+                }
+            },
+            None => rendered.push(match event {
+                Event::Start(Tag::CodeBlock(kind)) => {
+                    let language = match kind {
+                        CodeBlockKind::Fenced(info) => {
+                            info.split_whitespace().next().unwrap_or("").to_string()
+                        }
+                        CodeBlockKind::Indented => String::new(),
+                    };
+
+                    code_block = Some((language, String::new()));
+                    continue;
+                }
+                Event::Start(Tag::Heading(level, _, _)) => match headings.next() {
+                    Some(heading) => {
+                        Event::Html(CowStr::from(format!("<{} id=\"{}\">", level, heading.id)))
+                    }
+                    None => Event::Start(Tag::Heading(level, None, Vec::new())),
+                },
+                Event::Start(Tag::Link(kind, ref url, ref title)) if !is_safe_url(url) => {
+                    Event::Start(Tag::Link(kind, CowStr::from("#"), title.clone()))
+                }
+                Event::Start(Tag::Image(kind, ref url, ref title)) if !is_safe_url(url) => {
+                    Event::Start(Tag::Image(kind, CowStr::from(""), title.clone()))
+                }
+                // Raw HTML is shown as it was written
+                Event::Html(html) => Event::Text(html),
+                event => event,
+            }),
+        }
+    }
+
+    let mut output = String::new();
+    html::push_html(&mut output, rendered.into_iter());
+
+    Rendered { html: output, toc }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn relative_and_safe_urls_are_allowed() {
+        assert!(is_safe_url("https://example.com/"));
+        assert!(is_safe_url("http://example.com/a:b"));
+        assert!(is_safe_url("mailto:someone@example.com"));
+        assert!(is_safe_url("/relative/path"));
+        assert!(is_safe_url("other-upload"));
+        assert!(is_safe_url("#heading"));
+        assert!(is_safe_url("?query=a:b"));
+    }
+
+    #[test]
+    fn script_urls_are_rejected() {
+        assert!(!is_safe_url("javascript:alert(1)"));
+        assert!(!is_safe_url("JavaScript:alert(1)"));
+        assert!(!is_safe_url("  javascript:alert(1)"));
+        assert!(!is_safe_url("java\tscript:alert(1)"));
+        assert!(!is_safe_url("vbscript:msgbox(1)"));
+    }
+
+    #[test]
+    fn data_urls_are_rejected() {
+        assert!(!is_safe_url("data:text/html,<script>alert(1)</script>"));
+        assert!(!is_safe_url("DATA:image/svg+xml;base64,PHN2Zz4="));
+    }
+
+    #[test]
+    fn raw_html_is_escaped() {
+        let rendered = render(
+            "<script>alert(1)</script>\n\nText with <b onclick=\"x()\">inline</b> HTML",
+            &Highlighter::new(),
+        );
+
+        assert!(!rendered.html.contains("<script>"));
+        assert!(!rendered.html.contains("<b "));
+        assert!(rendered.html.contains("&lt;script&gt;"));
+        assert!(rendered.html.contains("&lt;b onclick=&quot;x()&quot;&gt;"));
+    }
+
+    #[test]
+    fn unsafe_links_and_images_are_removed() {
+        let rendered = render(
+            "[link](javascript:alert(1)) ![image](data:image/png;base64,AAAA)",
+            &Highlighter::new(),
+        );
+
+        assert!(!rendered.html.contains("javascript:"));
+        assert!(!rendered.html.contains("data:"));
+        assert!(rendered.html.contains("<a href=\"#\">link</a>"));
+    }
+
+    #[test]
+    fn headings_get_unique_anchors() {
+        let rendered = render("# Intro\n\n## Intro\n\n### A & B", &Highlighter::new());
+        let ids: Vec<&str> = rendered.toc.iter().map(|x| x.id.as_str()).collect();
+
+        assert_eq!(ids, vec!["md-intro", "md-intro-1", "md-a-b"]);
+        assert!(rendered.html.contains("<h1 id=\"md-intro\">"));
+    }
+}
@@ -0,0 +1,328 @@
+//! Serves files with support for HTTP range requests, so that large files can be seeked
+//! through and their downloads resumed.
+
+use std::cmp::min;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Cursor;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::str;
+
+use iron::headers::AcceptRanges;
+use iron::headers::ByteRangeSpec;
+use iron::headers::ContentLength;
+use iron::headers::ContentRange;
+use iron::headers::ContentRangeSpec;
+use iron::headers::ContentType;
+use iron::headers::RangeUnit;
+use iron::mime::{Attr, Mime, SubLevel, TopLevel, Value};
+use iron::prelude::*;
+use iron::response::BodyReader;
+use iron::status;
+
+use rand;
+use rand::Rng;
+
+/// Requests for more ranges than this are sent the whole file instead.
+const MAX_RANGES: usize = 16;
+
+/// The parts of a file requested by the Range header of a request.
+#[derive(Debug, PartialEq)]
+enum Requested {
+    /// The whole file, as no ranges (or no valid ones) were requested.
+    Whole,
+    /// None of the requested ranges are within the file.
+    Unsatisfiable,
+    /// The first and last byte of each requested range, in order and without overlaps.
+    Ranges(Vec<(u64, u64)>),
+}
+
+/// Parses the byte ranges requested by the value of a Range header. A header containing any
+/// malformed range, such as one which ends before it starts, is ignored entirely (RFC 7233,
+/// section 2.1), whereas Hyper only drops the malformed ranges, so the raw header is used.
+fn parse_ranges(header: &str) -> Option<Vec<ByteRangeSpec>> {
+    let header = header.trim();
+
+    if !header.starts_with("bytes=") {
+        return None;
+    }
+
+    let mut specs = Vec::new();
+
+    for spec in header["bytes=".len()..].split(',') {
+        match spec.trim() {
+            // Lists may contain empty elements
+            "" => (),
+            spec => specs.push(spec.parse::<ByteRangeSpec>().ok()?),
+        }
+    }
+
+    if specs.is_empty() || specs.len() > MAX_RANGES {
+        None
+    } else {
+        Some(specs)
+    }
+}
+
+/// Resolves a requested range against the length of a file, returning the first and last
+/// byte of the range if it can be satisfied.
+fn resolve_range(spec: &ByteRangeSpec, length: u64) -> Option<(u64, u64)> {
+    match *spec {
+        ByteRangeSpec::FromTo(from, to) if from < length => Some((from, min(to, length - 1))),
+        ByteRangeSpec::AllFrom(from) if from < length => Some((from, length - 1)),
+        ByteRangeSpec::Last(count) if count > 0 && length > 0 => {
+            Some((length - min(count, length), length - 1))
+        }
+        _ => None,
+    }
+}
+
+/// Finds the parts of a file of the specified length requested by the value of a Range
+/// header. Headers which are malformed, or which ask for too many ranges, are ignored as if
+/// they weren't sent. Overlapping and adjacent ranges are merged, so that no part of the file
+/// is sent twice.
+fn requested_ranges(header: Option<&str>, length: u64) -> Requested {
+    let specs = match header.and_then(parse_ranges) {
+        Some(specs) => specs,
+        None => return Requested::Whole,
+    };
+
+    let mut ranges: Vec<(u64, u64)> = specs
+        .iter()
+        .filter_map(|spec| resolve_range(spec, length))
+        .collect();
+
+    if ranges.is_empty() {
+        return Requested::Unsatisfiable;
+    }
+
+    ranges.sort();
+
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.0 <= last.1 + 1 => {
+                last.1 = last.1.max(range.1);
+                continue;
+            }
+            _ => (),
+        }
+
+        merged.push(range);
+    }
+
+    Requested::Ranges(merged)
+}
+
+/// Checks if a response sends a file from its beginning, i.e. is either the whole file or a
+/// single range starting at its first byte. Other ranges are usually parts of a download
+/// which has already started, such as seeking within a video.
+pub fn is_from_start(response: &Response) -> bool {
+    match response.status {
+        Some(status::PartialContent) => match response.headers.get::<ContentRange>() {
+            Some(&ContentRange(ContentRangeSpec::Bytes {
+                range: Some((0, _)),
+                ..
+            })) => true,
+            _ => false,
+        },
+        _ => true,
+    }
+}
+
+/// Opens a file for reading only the specified range of it.
+fn open_range(path: &Path, range: (u64, u64)) -> io::Result<io::Take<File>> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(range.0))?;
+    Ok(file.take(range.1 - range.0 + 1))
+}
+
+/// Builds a response for the contents of a file, sending only the parts of it requested by
+/// the Range header of the request, if any.
+pub fn file_response(req: &Request, path: &Path, content_type: Mime) -> io::Result<Response> {
+    let length = fs::metadata(path)?.len();
+
+    // Headers sent more than once can't be interpreted, so are ignored too
+    let header = match req.headers.get_raw("Range") {
+        Some(raw) if raw.len() == 1 => str::from_utf8(&raw[0]).ok(),
+        _ => None,
+    };
+
+    let ranges = match requested_ranges(header, length) {
+        Requested::Whole => Vec::new(),
+        Requested::Unsatisfiable => {
+            let mut response = Response::with(status::RangeNotSatisfiable);
+            response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+                range: None,
+                instance_length: Some(length),
+            }));
+            response.headers.set(AcceptRanges(vec![RangeUnit::Bytes]));
+            return Ok(response);
+        }
+        Requested::Ranges(ranges) => ranges,
+    };
+
+    let mut response = if ranges.is_empty() {
+        let mut response = Response::with((status::Ok, File::open(path)?));
+        response.headers.set(ContentType(content_type));
+        response
+    } else if ranges.len() == 1 {
+        let range = ranges[0];
+
+        let mut response =
+            Response::with((status::PartialContent, BodyReader(open_range(path, range)?)));
+        response.headers.set(ContentType(content_type));
+        response.headers.set(ContentLength(range.1 - range.0 + 1));
+        response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+            range: Some(range),
+            instance_length: Some(length),
+        }));
+        response
+    } else {
+        // Multiple ranges are sent as the parts of a multipart/byteranges body
+        let boundary = format!("{:016x}", rand::thread_rng().gen::<u64>());
+
+        let mut body: Box<dyn Read + Send> = Box::new(io::empty());
+        let mut body_length = 0;
+
+        for range in ranges {
+            let part_header = format!(
+                "\r\n--{}\r\nContent-Type: {}\r\nContent-Range: bytes {}-{}/{}\r\n\r\n",
+                boundary, content_type, range.0, range.1, length
+            );
+
+            body_length += part_header.len() as u64 + range.1 - range.0 + 1;
+            body = Box::new(
+                body.chain(Cursor::new(part_header.into_bytes()))
+                    .chain(open_range(path, range)?),
+            );
+        }
+
+        let footer = format!("\r\n--{}--\r\n", boundary);
+        body_length += footer.len() as u64;
+        body = Box::new(body.chain(Cursor::new(footer.into_bytes())));
+
+        let mut response = Response::with((status::PartialContent, BodyReader(body)));
+        response.headers.set(ContentType(Mime(
+            TopLevel::Multipart,
+            SubLevel::Ext("byteranges".into()),
+            vec![(Attr::Ext("boundary".into()), Value::Ext(boundary))],
+        )));
+        response.headers.set(ContentLength(body_length));
+        response
+    };
+
+    response.headers.set(AcceptRanges(vec![RangeUnit::Bytes]));
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn requested(header: &str, length: u64) -> Requested {
+        requested_ranges(Some(header), length)
+    }
+
+    #[test]
+    fn no_header_is_whole_file() {
+        assert_eq!(requested_ranges(None, 1000), Requested::Whole);
+    }
+
+    #[test]
+    fn single_ranges_are_resolved() {
+        let ranges = |x: (u64, u64)| Requested::Ranges(vec![x]);
+
+        assert_eq!(requested("bytes=0-99", 1000), ranges((0, 99)));
+        assert_eq!(requested("bytes=500-", 1000), ranges((500, 999)));
+        assert_eq!(requested("bytes=-100", 1000), ranges((900, 999)));
+    }
+
+    #[test]
+    fn ranges_are_clamped_to_file() {
+        let ranges = |x: (u64, u64)| Requested::Ranges(vec![x]);
+
+        assert_eq!(requested("bytes=900-2000", 1000), ranges((900, 999)));
+        assert_eq!(requested("bytes=-5000", 1000), ranges((0, 999)));
+    }
+
+    #[test]
+    fn malformed_headers_are_ignored() {
+        assert_eq!(requested("", 1000), Requested::Whole);
+        assert_eq!(requested("bytes=", 1000), Requested::Whole);
+        assert_eq!(requested("bytes=abc", 1000), Requested::Whole);
+        assert_eq!(requested("bytes=0-10,x", 1000), Requested::Whole);
+        assert_eq!(requested("items=0-10", 1000), Requested::Whole);
+    }
+
+    #[test]
+    fn reversed_ranges_are_ignored() {
+        assert_eq!(requested("bytes=500-100", 1000), Requested::Whole);
+        assert_eq!(requested("bytes=0-10,500-100", 1000), Requested::Whole);
+    }
+
+    #[test]
+    fn out_of_bounds_ranges_are_unsatisfiable() {
+        assert_eq!(requested("bytes=1000-1100", 1000), Requested::Unsatisfiable);
+        assert_eq!(requested("bytes=5000-", 1000), Requested::Unsatisfiable);
+        assert_eq!(requested("bytes=-0", 1000), Requested::Unsatisfiable);
+        assert_eq!(requested("bytes=0-10", 0), Requested::Unsatisfiable);
+    }
+
+    #[test]
+    fn out_of_bounds_ranges_are_dropped_alongside_others() {
+        assert_eq!(
+            requested("bytes=2000-3000,0-9", 1000),
+            Requested::Ranges(vec![(0, 9)])
+        );
+    }
+
+    #[test]
+    fn overlapping_ranges_are_merged() {
+        assert_eq!(
+            requested("bytes=0-99,50-149", 1000),
+            Requested::Ranges(vec![(0, 149)])
+        );
+        assert_eq!(
+            requested("bytes=500-599,0-99,100-199", 1000),
+            Requested::Ranges(vec![(0, 199), (500, 599)])
+        );
+        assert_eq!(
+            requested("bytes=0-999,10-20,-5", 1000),
+            Requested::Ranges(vec![(0, 999)])
+        );
+    }
+
+    #[test]
+    fn too_many_ranges_are_ignored() {
+        let specs: Vec<String> = (0..MAX_RANGES + 1)
+            .map(|x| format!("{}-{}", x * 10, x * 10 + 1))
+            .collect();
+        let header = format!("bytes={}", specs.join(","));
+
+        assert_eq!(requested(&header, 1000), Requested::Whole);
+    }
+
+    #[test]
+    fn responses_from_start_are_detected() {
+        let mut response = Response::with(status::PartialContent);
+        response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+            range: Some((0, 99)),
+            instance_length: Some(1000),
+        }));
+        assert!(is_from_start(&response));
+
+        response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+            range: Some((100, 199)),
+            instance_length: Some(1000),
+        }));
+        assert!(!is_from_start(&response));
+
+        assert!(is_from_start(&Response::with(status::Ok)));
+    }
+}
@@ -6,6 +6,7 @@ use auth::SessionStore;
 
 use types::StringError;
 
+use auth::hash_secret;
 use auth::User;
 
 use iron::modifiers::RedirectRaw;
@@ -15,8 +16,6 @@ use iron::status;
 use params::Params;
 use params::Value;
 
-use tiny_keccak::Keccak;
-
 use base64;
 
 use persistent;
@@ -34,13 +33,7 @@ pub fn login(req: &mut Request) -> IronResult<Response> {
         let password_str = extract_param_type!(map, String, "password")?;
 
         // Hash the inputted password
-        let mut password_hasher : Keccak = Keccak::new_sha3_256();
-        password_hasher.update(password_str.as_bytes());
-
-        let mut password : [u8; 32] = [0; 32];
-        password_hasher.finalize(&mut password);
-
-        let password = base64::encode(&password);
+        let password = base64::encode(&hash_secret(&[], password_str.as_bytes()));
 
         (username.to_string(), password)
     };
@@ -2,40 +2,244 @@
 
 use config::ConfigContainer;
 
+use auth::hash_secret;
+use auth::unlock_token;
+use auth::verify_secret_salted;
+use auth::verify_unlock_token;
+
 use types::FileMetadata;
 use types::FileType;
 use types::StringError;
 
+use assets::asset_hash;
+use assets::asset_variant;
 use assets::get_file;
 use assets::FILES as files;
+use assets::TEMPLATES;
+
+use ranges;
+
+use safety::is_origin;
+use safety::on_origin;
+use safety::protect;
+use safety::safe_content_type;
+
+use head::is_head;
+
+use stats::AccessStats;
+use stats::Hit;
+use stats::StatsContainer;
+
+use derivatives::derivative;
+use derivatives::is_transformable;
+use derivatives::GeneratorContainer;
+use derivatives::Transform;
+use derivatives::DEFAULT_CACHE_SIZE as DEFAULT_DERIVATIVE_CACHE_SIZE;
+use derivatives::DEFAULT_SIZES as DEFAULT_DERIVATIVE_SIZES;
+
+use routes::oembed::discovery_links;
+use routes::oembed::OEmbedLinks;
 
+use highlight::parse_line_range;
+use highlight::HighlighterContainer;
+use highlight::Line;
+
+use sniff::filename_extension;
+
+use markdown;
+use markdown::is_markdown_filename;
+use markdown::Rendered;
+
+use caching::cached_response;
+use caching::ContentClass;
+use caching::Validators;
+
+use compression::preferred_coding;
+use compression::set_vary;
+use compression::Compressible;
+
+use std::collections::HashMap;
 use std::fs::File;
+use std::io::ErrorKind;
 use std::io::Read;
 use std::path::Path;
+use std::sync::Arc;
+use std::sync::Mutex;
 
+use iron::headers::Accept;
+use iron::headers::AcceptRanges;
 use iron::headers::ContentDisposition;
 use iron::headers::DispositionParam;
 use iron::headers::DispositionType;
-use iron::mime::{Mime, SubLevel, TopLevel};
+use iron::headers::RangeUnit;
+use iron::headers::Referer;
+use iron::headers::UserAgent;
+use iron::mime::{Attr, Mime, SubLevel, TopLevel, Value as MimeValue};
 use iron::modifiers::Redirect;
+use iron::modifiers::RedirectRaw;
 use iron::prelude::*;
 use iron::status;
+use iron::typemap::Key;
 use iron::Url;
 
 use router::Router;
 
 use handlebars_iron::Template;
 
+use params::Params;
+use params::Value;
+
+use chrono::Local;
+
 use mime_guess;
 
 use persistent;
 
+/// Locks held while the views of a file with a view limit are being counted, so that
+/// concurrent requests cannot both claim the last view. Each file has its own lock.
+#[derive(Default)]
+pub struct ViewLocks {
+    locks: Mutex<HashMap<String, Arc<Mutex<()>>>>,
+}
+
+/// Contains the view locks shared by all requests.
+#[derive(Copy, Clone)]
+pub struct ViewLimitLock;
+
+impl Key for ViewLimitLock {
+    type Value = ViewLocks;
+}
+
+impl ViewLocks {
+    /// Runs a function while holding the lock for the specified file.
+    fn with_lock<T, F: FnOnce() -> T>(&self, name: &str, func: F) -> T {
+        let lock = self
+            .locks
+            .lock()
+            .unwrap_or_else(|x| x.into_inner())
+            .entry(name.to_owned())
+            .or_insert_with(|| Arc::new(Mutex::new(())))
+            .clone();
+
+        let result = {
+            let _guard = lock.lock().unwrap_or_else(|x| x.into_inner());
+            func()
+        };
+
+        // Locks are only handed out while the map is held, so one referenced only by the map
+        // and this request isn't wanted by anything else
+        let mut locks = self.locks.lock().unwrap_or_else(|x| x.into_inner());
+        if Arc::strong_count(&lock) == 2 {
+            locks.remove(name);
+        }
+
+        result
+    }
+}
+
+/// How long (in seconds) a password protected file stays unlocked for.
+const UNLOCK_TOKEN_LIFETIME: i64 = 15 * 60;
+
+/// Parts of the user agents of services which fetch links to build previews of them.
+const UNFURLERS: &[&str] = &[
+    "Slackbot",
+    "Discordbot",
+    "Twitterbot",
+    "facebookexternalhit",
+    "TelegramBot",
+    "WhatsApp",
+    "LinkedInBot",
+    "SkypeUriPreview",
+    "Mattermost",
+    "redditbot",
+    "Embedly",
+];
+
+/// How many characters of a paste are shown in its preview.
+const PREVIEW_LENGTH: usize = 200;
+
+/// The UnlockView is used as parameters to the unlock template.
+#[derive(Serialize)]
+struct UnlockView {
+    name: String,
+    error: bool,
+}
+
 /// The TextView is used as parameters to the login template.
 #[derive(Serialize)]
 struct TextView {
-    contents: String,
+    lines: Vec<Line>,
+    meta: FileMetadata,
+    url: String,
+    raw_link: String,
+    download_link: String,
+    qr_link: String,
+    oembed: OEmbedLinks,
+    markdown: Option<Rendered>,
+    toggle_link: Option<String>,
+}
+
+/// The GalleryView is used as parameters to the gallery template.
+#[derive(Serialize)]
+struct GalleryView {
+    name: String,
+    meta: FileMetadata,
+    items: Vec<GalleryItem>,
+    url: String,
+}
+
+/// A single member of a collection, as shown in the gallery template.
+#[derive(Serialize)]
+struct GalleryItem {
+    name: String,
+    link: String,
+    filename: String,
+    thumbnail: Option<String>,
+    image: bool,
+    video: bool,
+}
+
+/// The LinkView is used as parameters to the link template.
+#[derive(Serialize)]
+struct LinkView {
+    name: String,
+    url: String,
+    destination: String,
+    host: String,
+    secure: bool,
+    continue_link: String,
+    hits: u64,
+    meta: FileMetadata,
+}
+
+/// The PreviewView is used as parameters to the preview template.
+#[derive(Serialize)]
+struct PreviewView {
+    name: String,
+    title: String,
     meta: FileMetadata,
     url: String,
+    content_url: String,
+    description: Option<String>,
+    mime: Option<String>,
+    image: bool,
+    video: bool,
+    published: String,
+    oembed: OEmbedLinks,
+}
+
+/// The ways in which an upload can be viewed.
+#[derive(Copy, Clone, PartialEq)]
+enum View {
+    /// Pastes are rendered as HTML pages, and files are shown inline.
+    Page,
+    /// The contents as they were uploaded, with pastes sent as plain text.
+    Raw,
+    /// The contents as an attachment, so that browsers save them.
+    Download,
+    /// A page describing the upload, such as where a short link leads, requested by adding
+    /// "+" to its name.
+    Preview,
 }
 
 /// Helper function which attempts to find a static file enbedded in the executable.
@@ -87,45 +291,805 @@ pub fn get_pushed_file(req: &mut Request) -> IronResult<Response> {
     match file {
         // Send static file
         Some((buffer, content_type)) => {
-            return Ok(Response::with((content_type, status::Ok, buffer)));
+            // Clients which accept compression are sent the variant compressed at build time
+            let variant = preferred_coding(req).and_then(|coding| {
+                asset_variant(&path, coding.name()).map(|variant| (coding, variant))
+            });
+
+            // Each variant is a different representation, so needs its own ETag
+            let validators = Validators {
+                etag: asset_hash(&path).map(|hash| match variant {
+                    Some((coding, _)) => format!("{}-{}", hash, coding.name()),
+                    None => hash.to_string(),
+                }),
+                last_modified: None,
+            };
+
+            let mut response = cached_response(req, &validators, ContentClass::Asset, |_| {
+                Ok(match variant {
+                    Some((coding, variant)) => {
+                        let mut response = Response::with((content_type, status::Ok, variant));
+                        response.headers.set(coding.header());
+                        response
+                    }
+                    None => Response::with((content_type, status::Ok, buffer)),
+                })
+            })?;
+
+            set_vary(&mut response);
+            return Ok(response);
         }
         _ => {}
     }
 
+    // Adding "+" to a name previews it, instead of following it
+    if path.ends_with('+') {
+        return serve_upload(req, &base_path, &path[..path.len() - 1], View::Preview);
+    }
+
+    serve_upload(req, &base_path, &path, View::Page)
+}
+
+/// Raw file endpoint. Sends the contents of an upload as they were uploaded, with pastes
+/// sent as plain text rather than rendered.
+///
+/// HTTP request required state:
+///     Request kind: GET, with filename as part of path
+///     Headers: None
+pub fn get_raw_file(req: &mut Request) -> IronResult<Response> {
+    let (base_path, path) = upload_path(req);
+    serve_upload(req, &base_path, &path, View::Raw)
+}
+
+/// Download endpoint. Sends the contents of an upload as an attachment, named after the
+/// file that was originally uploaded.
+///
+/// HTTP request required state:
+///     Request kind: GET, with filename as part of path
+///     Headers: None
+pub fn download_file(req: &mut Request) -> IronResult<Response> {
+    let (base_path, path) = upload_path(req);
+    serve_upload(req, &base_path, &path, View::Download)
+}
+
+/// Finds the base path of uploads, and the name of the upload requested by a route.
+fn upload_path(req: &mut Request) -> (String, String) {
+    let base_path = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        config.base_path.to_owned()
+    };
+
+    let path = req
+        .extensions
+        .get::<Router>()
+        .unwrap()
+        .find("name")
+        .unwrap_or("")
+        .to_owned();
+
+    (base_path, path)
+}
+
+/// Serves an uploaded file in the requested view, once checking that it may be viewed.
+fn serve_upload(
+    req: &mut Request,
+    base_path: &str,
+    path: &str,
+    view: View,
+) -> IronResult<Response> {
     if path.contains("..") || path.contains("/") || path.contains("\\") {
         return Ok(Response::with(status::NotFound));
     }
 
-    let meta = FileMetadata::from_path(&base_path, &path).map_err(|x| {
+    let meta = FileMetadata::from_path(base_path, path).map_err(|x| {
         IronError::new(
             StringError(x),
             (status::NotFound, "Failed to find metadata"),
         )
     })?;
 
+    if meta.is_expired() {
+        return Ok(Response::with(status::Gone));
+    }
+
+    // Password protected files require a valid unlock token, else the user is asked for the
+    // password. Raw views are mostly fetched by scripts, so the token must be given up front.
+    match meta.password {
+        Some(ref password) if !is_unlocked(req, path, password) => {
+            if view == View::Raw || view == View::Download {
+                return Ok(Response::with((status::Forbidden, "Password required")));
+            }
+
+            return Ok(Response::with((
+                status::Ok,
+                Template::new(
+                    "unlock",
+                    &UnlockView {
+                        name: path.to_owned(),
+                        error: false,
+                    },
+                ),
+            )));
+        }
+        _ => (),
+    }
+
+    // Previews never count as views, as they don't contain the file itself. Short links are
+    // only previewed when asked to, so that link preview services follow them instead
+    let page = view == View::Page || view == View::Preview;
+    let requested = view == View::Preview || (page && has_param(req, "preview"));
+
     match meta.file_type {
-        FileType::File => {
-            let file = Path::new(&base_path).join(meta.actual_filename.unwrap());
+        FileType::File | FileType::Text if requested || (page && is_unfurler(req)) => {
+            let response = serve_preview(req, base_path, path, meta)?;
+            record_hit(req, path, &response);
+            return Ok(response);
+        }
+        FileType::Url if requested || (page && meta.interstitial == Some(true)) => {
+            if !has_param(req, "continue") {
+                return serve_interstitial(req, base_path, path, meta);
+            }
+        }
+        _ => (),
+    }
+
+    let view = match view {
+        View::Preview => View::Page,
+        view => view,
+    };
+
+    // With a separate user content origin, the contents of uploads are only served from there,
+    // while pages stay on the main origin
+    let (external_url, user_content_url) = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        (
+            config.external_url.to_owned(),
+            config.content_safety.user_content_url.to_owned(),
+        )
+    };
+
+    let origin = match (&meta.file_type, view) {
+        (FileType::File, _) | (FileType::Text, View::Raw) | (FileType::Text, View::Download) => {
+            user_content_url
+        }
+        (FileType::Text, View::Page) | (FileType::Collection, _) => {
+            user_content_url.map(|_| external_url)
+        }
+        _ => None,
+    };
+
+    match origin {
+        Some(ref origin) if !is_origin(req, origin) => {
+            return Ok(Response::with((
+                status::Found,
+                RedirectRaw(on_origin(req, origin)),
+            )));
+        }
+        _ => (),
+    }
+
+    let response = match meta.max_views {
+        Some(_) => serve_limited_file(req, base_path, path, view),
+        None => serve_file(req, base_path, path, meta, view),
+    }?;
+
+    record_hit(req, path, &response);
+    Ok(response)
+}
+
+/// Records an access of an upload in its stats once it has been served, which are saved in the
+/// background. Probes made with HEAD, revalidations, later parts of a download and images
+/// shown on this site's own pages aren't counted.
+fn record_hit(req: &mut Request, path: &str, response: &Response) {
+    let served = match response.status {
+        Some(status::NotModified) => false,
+        Some(status) if status.is_success() || status.is_redirection() => {
+            ranges::is_from_start(response)
+        }
+        _ => false,
+    };
+
+    if is_head(req) || !served || is_embedded_derivative(req) {
+        return;
+    }
+
+    let hit = Hit::from_request(req, path);
+
+    let arc = req.get::<persistent::Read<StatsContainer>>().unwrap();
+    arc.as_ref().record(hit);
+}
+
+/// Checks if a request is for a resized or converted image shown on one of this site's own
+/// pages, such as a gallery thumbnail, rather than a use of the upload's link.
+fn is_embedded_derivative(req: &mut Request) -> bool {
+    if !["w", "h", "fmt", "q"].iter().any(|x| has_param(req, x)) {
+        return false;
+    }
+
+    let referrer = match req.headers.get::<Referer>() {
+        Some(&Referer(ref referrer)) => match Url::parse(referrer) {
+            Ok(url) => url,
+            Err(_) => return false,
+        },
+        None => return false,
+    };
+
+    // Pages may be on a different origin to the images shown on them
+    let (external_url, user_content_url) = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        (
+            config.external_url.to_owned(),
+            config.content_safety.user_content_url.to_owned(),
+        )
+    };
+
+    let is_own_host = |url: &str| match Url::parse(url) {
+        Ok(url) => url.host() == referrer.host(),
+        Err(_) => false,
+    };
+
+    referrer.host() == req.url.host()
+        || is_own_host(&external_url)
+        || user_content_url.map_or(false, |x| is_own_host(&x))
+}
+
+/// Checks if a query parameter was given, with or without a value (e.g. "?preview").
+fn has_param(req: &mut Request, key: &str) -> bool {
+    let map = req.get_ref::<Params>().expect("No Params object available");
+    map.get(key).is_some()
+}
+
+/// Checks if a request is from a known link preview service which accepts HTML.
+fn is_unfurler(req: &Request) -> bool {
+    let unfurler = match req.headers.get::<UserAgent>() {
+        Some(&UserAgent(ref agent)) => UNFURLERS.iter().any(|x| agent.contains(x)),
+        None => false,
+    };
+
+    // Services fetching the upload itself, such as for an og:image, ask for that type instead
+    let accepts_html = match req.headers.get::<Accept>() {
+        Some(&Accept(ref accepted)) => accepted.iter().any(|x| match x.item {
+            Mime(TopLevel::Text, SubLevel::Html, _) => true,
+            Mime(TopLevel::Star, SubLevel::Star, _) => true,
+            _ => false,
+        }),
+        None => true,
+    };
+
+    unfurler && accepts_html
+}
+
+/// Builds a page describing an upload with OpenGraph and Twitter card metadata, for use in
+/// link previews. Private uploads are only described by their name.
+fn serve_preview(
+    req: &mut Request,
+    base_path: &str,
+    path: &str,
+    meta: FileMetadata,
+) -> IronResult<Response> {
+    let base_url = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        config.external_url.to_owned()
+    };
+
+    let private = is_private(&meta);
+    let class = page_class(&meta);
+
+    let content_type = match meta.file_type {
+        FileType::File => Some(meta.content_type()),
+        _ => None,
+    };
+
+    let (image, video) = match content_type {
+        Some(Mime(TopLevel::Image, _, _)) if !private => (true, false),
+        Some(Mime(TopLevel::Video, _, _)) if !private => (false, true),
+        _ => (false, false),
+    };
+
+    let description = match meta.file_type {
+        FileType::Text if !private => read_preview_text(base_path, &meta),
+        _ => None,
+    };
+
+    let url = base_url.clone() + path;
+
+    let preview = PreviewView {
+        name: path.to_owned(),
+        title: meta.filename.clone().unwrap_or_else(|| path.to_string()),
+        oembed: discovery_links(&base_url, &url),
+        url,
+        content_url: format!("{}raw/{}", base_url, path),
+        description,
+        mime: content_type.as_ref().map(|x| x.to_string()),
+        image,
+        video,
+        published: meta.date.to_rfc3339(),
+        meta,
+    };
+
+    cached_response(req, &Validators::none(), class, |_| {
+        Ok(Response::with((
+            status::Ok,
+            Template::new("preview", &preview),
+            Compressible,
+        )))
+    })
+}
+
+/// Shows where a short link leads, along with how often it has been followed, before the
+/// visitor chooses to continue to it.
+fn serve_interstitial(
+    req: &mut Request,
+    base_path: &str,
+    path: &str,
+    meta: FileMetadata,
+) -> IronResult<Response> {
+    let base_url = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        config.external_url.to_owned()
+    };
+
+    let destination = meta.url.clone().unwrap_or_default();
+    let target = Url::parse(&destination).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::InternalServerError, "Unable to build target URL"),
+        )
+    })?;
+
+    // The link has already been unlocked, so continuing to it is as well
+    let unlock = {
+        let map = req.get_ref::<Params>().expect("No Params object available");
+        match map.get("unlock") {
+            Some(&Value::String(ref token)) => format!("unlock={}", token),
+            _ => String::new(),
+        }
+    };
+
+    // Hits from the last few seconds may not have been saved yet
+    let hits = AccessStats::from_path(base_path, path).map_or(0, |x| x.hits);
+
+    let view = LinkView {
+        name: path.to_owned(),
+        url: base_url + path,
+        host: target.host().to_string(),
+        secure: target.scheme() == "https",
+        continue_link: with_query(format!("{}?continue", path), &unlock),
+        destination,
+        hits,
+        meta,
+    };
+
+    let class = page_class(&view.meta);
+
+    cached_response(req, &Validators::none(), class, |_| {
+        Ok(Response::with((
+            status::Ok,
+            Template::new("link", &view),
+            Compressible,
+        )))
+    })
+}
+
+/// Reads the start of a paste, to be shown in its preview.
+fn read_preview_text(base_path: &str, meta: &FileMetadata) -> Option<String> {
+    let path = Path::new(base_path).join(meta.actual_filename.clone()?);
+
+    let mut contents = String::new();
+    match File::open(&path).and_then(|mut file| file.read_to_string(&mut contents)) {
+        Ok(_) => (),
+        Err(_) => return None,
+    }
+
+    let mut preview: String = contents.chars().take(PREVIEW_LENGTH).collect();
+    if preview.len() < contents.len() {
+        preview.push_str("...");
+    }
+
+    Some(preview)
+}
+
+/// Appends a query to a link, if there is one.
+fn with_query(link: String, query: &str) -> String {
+    if query.is_empty() {
+        link
+    } else if link.contains("?") {
+        format!("{}&{}", link, query)
+    } else {
+        format!("{}?{}", link, query)
+    }
+}
+
+/// Checks if a request carries a valid unlock token for the specified file.
+fn is_unlocked(req: &mut Request, name: &str, password_hash: &str) -> bool {
+    let key = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        config.key.to_owned()
+    };
+
+    let map = req.get_ref::<Params>().expect("No Params object available");
+
+    let token: IronResult<&str> = extract_param_type!(map, String, "unlock");
+    match token {
+        Ok(token) => {
+            verify_unlock_token(&key, name, password_hash, token, Local::now().timestamp())
+        }
+        Err(_) => false,
+    }
+}
+
+/// Unlock endpoint. Checks the password for a password protected file, redirecting back to
+/// the file with a short-lived unlock token if it is correct.
+///
+/// HTTP request required state:
+///     Request kind: POST, with filename as part of path, and a key-value pair of password
+///     Headers: None
+pub fn unlock_pushed_file(req: &mut Request) -> IronResult<Response> {
+    let (base_path, key) = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        (config.base_path.to_owned(), config.key.to_owned())
+    };
+
+    let path = req
+        .extensions
+        .get::<Router>()
+        .unwrap()
+        .find("")
+        .unwrap_or("")
+        .to_owned();
+
+    if path.contains("..") || path.contains("/") || path.contains("\\") {
+        return Ok(Response::with(status::NotFound));
+    }
+
+    let meta = FileMetadata::from_path(&base_path, &path).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::NotFound, "Failed to find metadata"),
+        )
+    })?;
+
+    if meta.is_expired() {
+        return Ok(Response::with(status::Gone));
+    }
+
+    let password_hash = match meta.password {
+        Some(password_hash) => password_hash,
+        // Nothing to unlock
+        None => return Ok(Response::with((status::Found, RedirectRaw(path)))),
+    };
+
+    let password = {
+        let map = req.get_ref::<Params>().expect("No Params object available");
+        extract_param_type!(map, String, "password")?.to_owned()
+    };
+
+    if !verify_secret_salted(&password_hash, &password) {
+        println!("Failed unlock attempt for {:?}.", path);
+
+        return Ok(Response::with((
+            status::Forbidden,
+            Template::new(
+                "unlock",
+                &UnlockView {
+                    name: path,
+                    error: true,
+                },
+            ),
+        )));
+    }
+
+    let token = unlock_token(
+        &key,
+        &path,
+        &password_hash,
+        Local::now().timestamp() + UNLOCK_TOKEN_LIFETIME,
+    );
+
+    Ok(Response::with((
+        status::Found,
+        RedirectRaw(format!("{}?unlock={}", path, token)),
+    )))
+}
+
+/// Claims a view of a file with a view limit, returning its metadata from before the view
+/// was claimed, or None if it has already run out of views.
+fn claim_view(base_path: &str, path: &str) -> IronResult<Option<FileMetadata>> {
+    // Another request may have claimed a view since the metadata was last read
+    let meta = FileMetadata::from_path(base_path, path).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::NotFound, "Failed to find metadata"),
+        )
+    })?;
+
+    if meta.views_exhausted() {
+        return Ok(None);
+    }
+
+    let mut claimed = meta.clone();
+    claimed.views = Some(claimed.views.unwrap_or(0) + 1);
+
+    claimed.save(base_path, path).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::InternalServerError, "Failed to update metadata"),
+        )
+    })?;
+
+    Ok(Some(meta))
+}
+
+/// Finishes a view claimed by a request, returning the view if the file wasn't sent, or
+/// removing the file if this was its last view.
+fn finish_view(base_path: &str, path: &str, sent: bool) -> Result<(), String> {
+    let mut meta = FileMetadata::from_path(base_path, path)?;
+
+    if !sent {
+        meta.views = Some(meta.views.unwrap_or(0).saturating_sub(1));
+        meta.save(base_path, path)
+    } else if meta.views_exhausted() {
+        // Files are already open by this point, so can be safely removed while being sent
+        println!("File {:?} has run out of views, removing...", path);
+        meta.remove(base_path, path)
+    } else {
+        Ok(())
+    }
+}
+
+/// Serves a file which can only be viewed a limited number of times, counting this view and
+/// removing the file once it has run out of views.
+fn serve_limited_file(
+    req: &mut Request,
+    base_path: &str,
+    path: &str,
+    view: View,
+) -> IronResult<Response> {
+    // HEAD requests don't send the file, so don't claim a view
+    if is_head(req) {
+        let meta = FileMetadata::from_path(base_path, path).map_err(|x| {
+            IronError::new(
+                StringError(x),
+                (status::NotFound, "Failed to find metadata"),
+            )
+        })?;
+
+        if meta.views_exhausted() {
+            return Ok(Response::with(status::Gone));
+        }
+
+        return serve_file(req, base_path, path, meta, view);
+    }
+
+    // Every request uses up a view, so the whole file is always sent. Otherwise, it could be
+    // read in parts for the price of one view
+    req.headers.remove_raw("Range");
+
+    let locks = req.get::<persistent::Read<ViewLimitLock>>().unwrap();
+
+    // The view is claimed before the file is sent, so that the lock isn't held while it is
+    let meta = match locks.with_lock(path, || claim_view(base_path, path))? {
+        Some(meta) => meta,
+        None => return Ok(Response::with(status::Gone)),
+    };
+
+    let mut response = serve_file(req, base_path, path, meta, view);
+
+    // Only successful views count towards the limit
+    let sent = match response {
+        Ok(ref mut response) => {
+            if response.headers.has::<AcceptRanges>() {
+                response.headers.set(AcceptRanges(vec![RangeUnit::None]));
+            }
+
+            match response.status {
+                Some(status) => status.is_success() || status.is_redirection(),
+                None => false,
+            }
+        }
+        _ => false,
+    };
+
+    locks
+        .with_lock(path, || finish_view(base_path, path, sent))
+        .map_err(|x| {
+            IronError::new(
+                StringError(x),
+                (status::InternalServerError, "Failed to update metadata"),
+            )
+        })?;
+
+    response
+}
+
+/// Identifies a page rendered for a paste, from its contents and the options which it was
+/// rendered with. The templates are included too, so that pages rendered by other versions of
+/// them aren't reused.
+fn text_etag(meta: &FileMetadata, options: &[&str]) -> String {
+    // Pastes can't be changed once uploaded, so those without a hash of their contents are
+    // identified by when they were uploaded instead
+    let mut input = meta
+        .hash
+        .clone()
+        .unwrap_or_else(|| meta.date.to_rfc3339())
+        .into_bytes();
+
+    for option in options {
+        input.push(0);
+        input.extend_from_slice(option.as_bytes());
+    }
+
+    for template in &["text.hbs", "header.hbs", "footer.hbs"] {
+        input.push(0);
+        input.extend_from_slice(get_file(&TEMPLATES, template).unwrap_or_default());
+    }
+
+    hash_secret(&[], &input)[..16]
+        .iter()
+        .map(|x| format!("{:02x}", x))
+        .collect()
+}
+
+/// Checks if an upload is password protected or view limited, and so shouldn't be cached.
+fn is_private(meta: &FileMetadata) -> bool {
+    meta.password.is_some() || meta.max_views.is_some()
+}
+
+/// Returns the class of content which an uploaded file is served as.
+fn content_class(meta: &FileMetadata) -> ContentClass {
+    if is_private(meta) {
+        ContentClass::Private
+    } else {
+        ContentClass::Upload
+    }
+}
+
+/// Returns the class of content which a page rendered for an upload is served as.
+fn page_class(meta: &FileMetadata) -> ContentClass {
+    if is_private(meta) {
+        ContentClass::Private
+    } else {
+        ContentClass::Page
+    }
+}
+
+/// Builds a response for the contents of an uploaded file, as the specified view.
+fn serve_file(
+    req: &mut Request,
+    base_path: &str,
+    path: &str,
+    meta: FileMetadata,
+    view: View,
+) -> IronResult<Response> {
+    match (&meta.file_type, view) {
+        (FileType::File, _) | (FileType::Text, View::Raw) | (FileType::Text, View::Download) => {
+            let mut file = Path::new(&base_path).join(meta.actual_filename.clone().unwrap());
 
             if file.exists() {
-                let content_type = mime_guess::guess_mime_type(&file);
-
-                let mut response = Response::with((content_type, status::Ok, file));
-                response.headers.set(ContentDisposition {
-                    disposition: DispositionType::Inline,
-                    parameters: vec![DispositionParam::Ext(
-                        format!("filename"),
-                        meta.filename
+                // Every view of a view limited file has to reach the server to be counted
+                let mut validators = if is_private(&meta) {
+                    Validators::none()
+                } else {
+                    Validators {
+                        etag: meta.hash.clone(),
+                        last_modified: Some(meta.date),
+                    }
+                };
+
+                // Pastes are always sent as text, so that they are never run by browsers
+                let content_type = match meta.file_type {
+                    FileType::Text => Mime(
+                        TopLevel::Text,
+                        SubLevel::Plain,
+                        vec![(Attr::Charset, MimeValue::Utf8)],
+                    ),
+                    _ => meta.content_type(),
+                };
+
+                let disposition = match view {
+                    View::Download => DispositionType::Attachment,
+                    _ => DispositionType::Inline,
+                };
+
+                let policy = {
+                    let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+                    let config = arc.as_ref();
+                    config.content_safety.active_content
+                };
+
+                let (mut content_type, disposition) =
+                    safe_content_type(content_type, disposition, policy);
+
+                let mut filename = meta.filename.clone().unwrap_or_else(|| path.to_string());
+
+                // Images can be resized or converted, but downloads are always of the original
+                let (sizes, max_cache_size) = {
+                    let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+                    let config = arc.as_ref();
+                    (
+                        config
+                            .derivative_sizes
                             .clone()
-                            .expect("Should have filename for File type"),
-                    )],
+                            .unwrap_or_else(|| DEFAULT_DERIVATIVE_SIZES.to_vec()),
+                        config
+                            .derivative_cache_size
+                            .unwrap_or(DEFAULT_DERIVATIVE_CACHE_SIZE),
+                    )
+                };
+
+                let transform = match (&meta.file_type, view) {
+                    (FileType::File, View::Page) | (FileType::File, View::Raw)
+                        if is_transformable(&content_type) =>
+                    {
+                        Transform::from_request(req, &sizes)?
+                    }
+                    _ => None,
+                };
+
+                match transform {
+                    Some(transform) => {
+                        let generator = req.get::<persistent::Read<GeneratorContainer>>().unwrap();
+
+                        let derivative = derivative(
+                            base_path,
+                            path,
+                            &meta,
+                            &transform,
+                            &generator,
+                            max_cache_size,
+                        )
+                        .map_err(|x| match x.kind() {
+                            ErrorKind::InvalidData => IronError::new(
+                                x,
+                                (status::UnprocessableEntity, "Unable to convert image"),
+                            ),
+                            _ => IronError::new(
+                                x,
+                                (status::InternalServerError, "Internal I/O error"),
+                            ),
+                        })?;
+
+                        validators.etag = validators
+                            .etag
+                            .map(|hash| format!("{}-{}", hash, derivative.key));
+                        content_type = derivative.format.mime();
+                        filename = match filename.rfind('.') {
+                            Some(index) => {
+                                format!("{}.{}", &filename[..index], derivative.format.extension())
+                            }
+                            None => format!("{}.{}", filename, derivative.format.extension()),
+                        };
+                        file = derivative.path;
+                    }
+                    None => (),
+                }
+
+                return cached_response(req, &validators, content_class(&meta), |req| {
+                    let mut response =
+                        ranges::file_response(req, &file, content_type).map_err(|x| {
+                            IronError::new(x, (status::InternalServerError, "Internal I/O error"))
+                        })?;
+                    response.headers.set(ContentDisposition {
+                        disposition,
+                        parameters: vec![DispositionParam::Ext(format!("filename"), filename)],
+                    });
+                    protect(&mut response);
+                    Ok(response)
                 });
-                return Ok(response);
             } else {
                 return Ok(Response::with(status::NotFound));
             }
         }
-        FileType::Url => {
+        // Links and collections have no contents of their own
+        (_, View::Raw) | (_, View::Download) => return Ok(Response::with(status::NotFound)),
+        (FileType::Url, _) => {
             return Ok(Response::with((
                 status::Found,
                 Redirect(Url::parse(&meta.url.unwrap()).map_err(|x| {
@@ -136,46 +1100,338 @@ pub fn get_pushed_file(req: &mut Request) -> IronResult<Response> {
                 })?),
             )));
         }
-        FileType::Text => {
-            let base_url = {
+        (FileType::Text, _) => {
+            let (base_url, key) = {
                 let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
                 let config = arc.as_ref();
-                config.external_url.to_owned()
+                (config.external_url.to_owned(), config.key.to_owned())
+            };
+
+            let url = base_url.clone() + path;
+            let oembed = discovery_links(&base_url, &url);
+
+            // The page has already been unlocked, so its other views are as well
+            let unlock = match meta.password {
+                Some(ref password_hash) => format!(
+                    "unlock={}",
+                    unlock_token(
+                        &key,
+                        path,
+                        password_hash,
+                        Local::now().timestamp() + UNLOCK_TOKEN_LIFETIME
+                    )
+                ),
+                None => String::new(),
+            };
+
+            let raw_link = with_query(format!("raw/{}", path), &unlock);
+            let download_link = with_query(format!("dl/{}", path), &unlock);
+            let qr_link = format!("qr/{}", path);
+
+            // Markdown files are rendered unless their source is asked for, and other pastes
+            // can be rendered on request
+            let is_markdown = match meta.filename {
+                Some(ref filename) => is_markdown_filename(filename),
+                None => false,
+            };
+
+            let render_markdown = {
+                let map = req.get_ref::<Params>().expect("No Params object available");
+                match map.get("render") {
+                    Some(&Value::String(ref mode)) => mode == "markdown",
+                    _ => is_markdown,
+                }
+            };
+
+            let toggle_link = if render_markdown {
+                Some(with_query(format!("{}?render=source", path), &unlock))
+            } else if is_markdown {
+                Some(with_query(format!("{}?render=markdown", path), &unlock))
+            } else {
+                None
+            };
+
+            let selected = {
+                let map = req.get_ref::<Params>().expect("No Params object available");
+                match map.get("lines") {
+                    Some(&Value::String(ref range)) if !render_markdown => parse_line_range(range),
+                    _ => None,
+                }
             };
 
-            let url = base_url + &path;
+            // Explicit languages take precedence over the extension the paste was stored with
+            let language = meta.language.clone().or_else(|| {
+                meta.actual_filename
+                    .as_ref()
+                    .and_then(|x| filename_extension(x))
+            });
 
-            // Read in text file
+            // Pages of password protected pastes contain unlock tokens, so are never reused
+            let validators = if is_private(&meta) {
+                Validators::none()
+            } else {
+                let mode = if render_markdown {
+                    "markdown"
+                } else {
+                    "source"
+                };
+                let range = selected
+                    .map(|(first, last)| format!("{}-{}", first, last))
+                    .unwrap_or_default();
+                let language = language.as_ref().map(|x| x.as_str()).unwrap_or("");
+
+                // Pages are compressed for clients which accept it, and each content coding is
+                // a different representation, so needs its own ETag
+                let coding = preferred_coding(req).map_or("identity", |x| x.name());
+
+                Validators {
+                    etag: Some(text_etag(&meta, &[path, language, mode, &range, coding])),
+                    last_modified: None,
+                }
+            };
+
+            let class = page_class(&meta);
             let meta_filename = base_path.to_string() + &meta.actual_filename.clone().unwrap();
-            let path = Path::new(&meta_filename);
-            let mut meta_file = match File::open(&path) {
-                Err(_) => {
-                    println!("File {:?} doesn't exist!", path);
-                    return Ok(Response::with(status::NotFound));
+
+            // Pastes are only read and highlighted if the client doesn't have the page already
+            return cached_response(req, &validators, class, |req| {
+                let path = Path::new(&meta_filename);
+                let mut meta_file = match File::open(&path) {
+                    Err(_) => {
+                        println!("File {:?} doesn't exist!", path);
+                        return Ok(Response::with(status::NotFound));
+                    }
+                    Ok(file) => file,
+                };
+
+                let mut meta_string = String::new();
+                match meta_file.read_to_string(&mut meta_string) {
+                    Err(_) => {
+                        println!("File {:?} is unreadable!", path);
+                        return Ok(Response::with(status::NotFound));
+                    }
+                    Ok(_) => (),
                 }
-                Ok(file) => file,
+
+                let highlighter = req.get::<persistent::Read<HighlighterContainer>>().unwrap();
+
+                let (markdown, lines) = if render_markdown {
+                    (
+                        Some(markdown::render(&meta_string, &highlighter)),
+                        Vec::new(),
+                    )
+                } else {
+                    let lines = highlighter.highlight_lines(
+                        &meta_string,
+                        language.as_ref().map(|x| x.as_str()),
+                        selected,
+                    );
+                    (None, lines)
+                };
+
+                Ok(Response::with((
+                    status::Ok,
+                    Template::new(
+                        "text",
+                        &TextView {
+                            lines,
+                            meta,
+                            url,
+                            raw_link,
+                            download_link,
+                            qr_link,
+                            oembed,
+                            markdown,
+                            toggle_link,
+                        },
+                    ),
+                    Compressible,
+                )))
+            });
+        }
+        (FileType::Collection, _) => {
+            let (base_url, key) = {
+                let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+                let config = arc.as_ref();
+                (config.external_url.to_owned(), config.key.to_owned())
             };
 
-            let mut meta_string = String::new();
-            match meta_file.read_to_string(&mut meta_string) {
-                Err(_) => {
-                    println!("File {:?} is unreadable!", path);
-                    return Ok(Response::with(status::NotFound));
+            let url = base_url + path;
+            let unlock_expires = Local::now().timestamp() + UNLOCK_TOKEN_LIFETIME;
+
+            let mut items = Vec::new();
+
+            for member in meta.members.clone().unwrap_or_default() {
+                // Members may have been removed or renamed since the collection was created
+                let member_meta = match FileMetadata::from_path(base_path, &member) {
+                    Ok(member_meta) => member_meta,
+                    Err(_) => continue,
+                };
+
+                if member_meta.is_expired() {
+                    continue;
                 }
-                Ok(_) => (),
-            }
 
-            return Ok(Response::with((
-                status::Ok,
-                Template::new(
-                    "text",
-                    &TextView {
-                        contents: meta_string,
-                        meta,
-                        url,
+                // The collection has already been unlocked, so members sharing its password are
+                // as well. Names can be reused, so others may belong to someone else entirely
+                let link = match member_meta.password {
+                    Some(ref password_hash) if member_meta.password == meta.password => format!(
+                        "{}?unlock={}",
+                        member,
+                        unlock_token(&key, &member, password_hash, unlock_expires)
+                    ),
+                    _ => member.clone(),
+                };
+
+                let content_type = match member_meta.file_type {
+                    FileType::File => Some(member_meta.content_type()),
+                    _ => None,
+                };
+
+                items.push(GalleryItem {
+                    filename: member_meta
+                        .filename
+                        .clone()
+                        .unwrap_or_else(|| member.clone()),
+                    name: member,
+                    thumbnail: match content_type {
+                        Some(ref mime) if is_transformable(mime) => {
+                            Some(with_query(link.clone(), "w=600"))
+                        }
+                        _ => None,
                     },
-                ),
-            )));
+                    link,
+                    image: match content_type {
+                        Some(Mime(TopLevel::Image, _, _)) => true,
+                        _ => false,
+                    },
+                    video: match content_type {
+                        Some(Mime(TopLevel::Video, _, _)) => true,
+                        _ => false,
+                    },
+                });
+            }
+
+            let class = page_class(&meta);
+
+            return cached_response(req, &Validators::none(), class, |_| {
+                Ok(Response::with((
+                    status::Ok,
+                    Template::new(
+                        "gallery",
+                        &GalleryView {
+                            name: path.to_owned(),
+                            meta,
+                            items,
+                            url,
+                        },
+                    ),
+                    Compressible,
+                )))
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+    use std::mem;
+    use std::net::TcpStream;
+
+    use iron::method;
+    use iron::Iron;
+
+    use router::Router;
+
+    use toml;
+
+    use config::Config;
+
+    use stats::spawn_recorder;
+
+    /// Starts a server for uploads within the specified root, returning its address.
+    fn serve(root: &str) -> String {
+        let config: Config = toml::from_str(&format!(
+            "bind_addr = \"127.0.0.1:0\"\n\
+             external_url = \"http://127.0.0.1/\"\n\
+             base_path = {:?}\n\
+             api_keys = []\n\
+             users = []\n\
+             key = \"key\"\n",
+            root
+        ))
+        .unwrap();
+
+        let mut router = Router::new();
+        router.route(method::Get, "/raw/:name", get_raw_file, "raw");
+
+        let recorder = spawn_recorder(root.to_string());
+
+        let mut chain = Chain::new(router);
+        chain.link(persistent::Read::<StatsContainer>::both(recorder));
+        chain.link(persistent::Read::<ConfigContainer>::both(config));
+        chain.link(persistent::Read::<ViewLimitLock>::both(ViewLocks::default()));
+
+        let listening = Iron::new(chain).http("127.0.0.1:0").unwrap();
+        let address = listening.socket.to_string();
+
+        // Dropping the server waits for it to stop, which it never does
+        mem::forget(listening);
+        address
+    }
+
+    /// Requests an upload with the specified extra headers, returning the response's status line.
+    fn get(address: &str, name: &str, headers: &str) -> String {
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "GET /raw/{} HTTP/1.0\r\nHost: {}\r\n{}\r\n",
+            name, address, headers
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response.lines().next().unwrap_or_default().to_string()
+    }
+
+    /// Creates a text file upload which can be viewed once.
+    fn one_view_upload(root: &str, name: &str) {
+        let root = Path::new(root);
+
+        fs::write(root.join(format!("{}.txt", name)), "secret contents").unwrap();
+        fs::write(
+            root.join(format!("{}.info.json", name)),
+            format!(
+                "{{\"date\":\"Mon, 11 Dec 2017 10:28:36 +0000\",\"type\":\"file\",\
+                 \"filename\":\"{0}.txt\",\"actual_filename\":\"{0}.txt\",\"max_views\":1}}",
+                name
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn ranges_use_up_limited_views() {
+        let root = env::temp_dir().join("liftpush-limited-ranges");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let root = root.to_str().unwrap();
+
+        let address = serve(root);
+
+        for (name, range) in &[("Multi", "bytes=0-0,1-"), ("Later", "bytes=1-")] {
+            one_view_upload(root, name);
+
+            let header = format!("Range: {}\r\n", range);
+            assert!(get(&address, name, &header).contains(" 200 "));
+            assert!(get(&address, name, "").contains(" 404 "));
+            assert!(!Path::new(root).join(format!("{}.txt", name)).exists());
         }
     }
 }
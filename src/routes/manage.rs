@@ -4,11 +4,12 @@ use config::ConfigContainer;
 
 use auth::SessionStore;
 
+use compression::Compressible;
+
 use types::FileMetadata;
 use types::StringError;
 
-use std::fs;
-use std::fs::DirEntry;
+use stats::AccessStats;
 
 use iron::prelude::*;
 use iron::status;
@@ -24,6 +25,7 @@ use serde_json;
 struct ManageMetadata {
     name: String,
     meta: FileMetadata,
+    stats: AccessStats,
 }
 
 /// Metadata for all files.
@@ -76,28 +78,23 @@ pub fn listing(req: &mut Request) -> IronResult<Response> {
         )
     })?;
 
-    let paths = fs::read_dir(&base_path).unwrap();
+    let names = FileMetadata::list_names(&base_path).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::InternalServerError, "Internal I/O error"),
+        )
+    })?;
 
     let mut found_files: Vec<ManageMetadata> = Vec::new();
 
-    for path in paths {
-        let path: DirEntry = path.unwrap();
-        let path_filename = path.file_name();
-        let filename = path_filename.to_str().unwrap();
-
-        if filename.ends_with(".info.json") {
-            let name: Vec<&str> = filename.split(".").collect::<Vec<_>>();
-            let name = name[0..name.len() - 2].join(".");
-
-            match FileMetadata::from_path(&base_path, &name) {
-                Ok(meta) => {
-                    found_files.push(ManageMetadata {
-                        name: name.to_string(),
-                        meta,
-                    });
-                }
-                Err(v) => eprintln!("Failed to open file {:?}: {:?}", filename, v),
+    for name in names {
+        match FileMetadata::from_path(&base_path, &name) {
+            Ok(meta) => {
+                // Hits from the last few seconds may not have been saved yet
+                let stats = AccessStats::from_path(&base_path, &name).unwrap_or_default();
+                found_files.push(ManageMetadata { name, meta, stats });
             }
+            Err(v) => eprintln!("Failed to open file {:?}: {:?}", name, v),
         }
     }
 
@@ -107,5 +104,6 @@ pub fn listing(req: &mut Request) -> IronResult<Response> {
         status::Ok,
         serde_json::to_string(&FileListing { files: found_files })
             .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?,
+        Compressible,
     )))
 }
@@ -27,4 +27,6 @@ pub mod files;
 pub mod homepage;
 pub mod manage;
 pub mod modify;
+pub mod oembed;
+pub mod qr;
 pub mod upload;
@@ -2,9 +2,16 @@
 
 use config::ConfigContainer;
 
+use auth::verify_secret_salted;
+use auth::SessionStore;
+
+use types::is_reserved_name;
+use types::is_valid_name;
 use types::FileMetadata;
 use types::StringError;
 
+use stats::rename_stats;
+
 use std::fs;
 use std::fs::File;
 use std::io::Write;
@@ -32,6 +39,13 @@ pub fn delete_file(req: &mut Request) -> IronResult<Response> {
         config.base_path.to_owned()
     };
 
+    req.extensions.get::<SessionStore>().ok_or_else(|| {
+        IronError::new(
+            StringError("User attempted to access restricted page".into()),
+            (status::Unauthorized, "You are not logged in"),
+        )
+    })?;
+
     let file = req
         .extensions
         .get::<Router>()
@@ -44,7 +58,7 @@ pub fn delete_file(req: &mut Request) -> IronResult<Response> {
             )
         })?;
 
-    if file.contains(".") || file.contains("/") || file.contains("\\") {
+    if !is_valid_name(file) {
         return Ok(Response::with(status::NotFound));
     }
 
@@ -57,12 +71,92 @@ pub fn delete_file(req: &mut Request) -> IronResult<Response> {
 
     println!("Deleting file {:?}...", file);
 
-    match meta.actual_filename {
-        Some(name) => fs::remove_file(Path::new(&base_path).join(name)).unwrap(),
-        _ => {}
+    meta.remove(&base_path, &file).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::InternalServerError, "Failed to delete file"),
+        )
+    })?;
+
+    Ok(Response::with((status::Ok, "Deleted")))
+}
+
+/// Token delete endpoint. Deletes the specified file using the deletion token returned
+/// when it was uploaded, without requiring a login. Deleting a collection also deletes the
+/// files which were uploaded alongside it.
+///
+/// HTTP request required state:
+///     Request kind: GET, with filename and deletion token as part of path
+///     Headers: None
+pub fn delete_file_with_token(req: &mut Request) -> IronResult<Response> {
+    let base_path = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        config.base_path.to_owned()
+    };
+
+    let router = req.extensions.get::<Router>().unwrap();
+
+    let file = router.find("file").ok_or_else(|| {
+        IronError::new(
+            StringError("No file specified for delete operation".into()),
+            (status::NotFound, "No file specified"),
+        )
+    })?;
+
+    let token = router.find("token").ok_or_else(|| {
+        IronError::new(
+            StringError("No token specified for delete operation".into()),
+            (status::NotFound, "No token specified"),
+        )
+    })?;
+
+    if !is_valid_name(file) {
+        return Ok(Response::with(status::NotFound));
     }
 
-    fs::remove_file(Path::new(&base_path).join(format!("{}.info.json", file))).unwrap();
+    let meta = FileMetadata::from_path(&base_path, &file).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::NotFound, "Failed to find metadata"),
+        )
+    })?;
+
+    let delete_token = match meta.delete_token {
+        Some(ref delete_token) if verify_secret_salted(delete_token, token) => delete_token,
+        _ => {
+            return Err(IronError::new(
+                StringError(format!("Bad deletion token for {:?}", file)),
+                (status::Forbidden, "Invalid deletion token"),
+            ))
+        }
+    };
+
+    // Members share the collection's token if they were uploaded alongside it
+    for member in meta.members.clone().unwrap_or_default() {
+        match FileMetadata::from_path(&base_path, &member) {
+            Ok(ref member_meta) if member_meta.delete_token.as_ref() == Some(delete_token) => {
+                println!("Deleting file {:?} from collection {:?}...", member, file);
+
+                member_meta.remove(&base_path, &member).map_err(|x| {
+                    IronError::new(
+                        StringError(x),
+                        (status::InternalServerError, "Failed to delete file"),
+                    )
+                })?;
+            }
+            _ => (),
+        }
+    }
+
+    println!("Deleting file {:?} using its deletion token...", file);
+
+    meta.remove(&base_path, &file).map_err(|x| {
+        IronError::new(
+            StringError(x),
+            (status::InternalServerError, "Failed to delete file"),
+        )
+    })?;
 
     Ok(Response::with((status::Ok, "Deleted")))
 }
@@ -79,6 +173,13 @@ pub fn rename_file(req: &mut Request) -> IronResult<Response> {
         config.base_path.to_owned()
     };
 
+    req.extensions.get::<SessionStore>().ok_or_else(|| {
+        IronError::new(
+            StringError("User attempted to access restricted page".into()),
+            (status::Unauthorized, "You are not logged in"),
+        )
+    })?;
+
     let router = req.extensions.get::<Router>().unwrap();
 
     let file = router.find("source").ok_or_else(|| {
@@ -95,7 +196,7 @@ pub fn rename_file(req: &mut Request) -> IronResult<Response> {
         )
     })?;
 
-    if file.contains(".") || file.contains("/") || file.contains("\\") {
+    if !is_valid_name(file) {
         return Err(IronError::new(
             StringError(format!("Source path {:?} contains bad characters", file)),
             status::NotFound,
@@ -109,13 +210,20 @@ pub fn rename_file(req: &mut Request) -> IronResult<Response> {
         )
     })?;
 
-    if to.contains(".") || to.contains("/") || to.contains("\\") {
+    if !is_valid_name(to) {
         return Err(IronError::new(
             StringError(format!("Target path {:?} contains bad characters", to)),
             status::NotFound,
         ));
     }
 
+    if is_reserved_name(to) {
+        return Err(IronError::new(
+            StringError(format!("Target path {:?} is reserved", to)),
+            (status::Conflict, "Name already in use"),
+        ));
+    }
+
     match meta.actual_filename.take() {
         Some(name) => {
             let new_filename = match name.split(".").next() {
@@ -147,7 +255,7 @@ pub fn rename_file(req: &mut Request) -> IronResult<Response> {
 
     let target = to.split(".").next().unwrap().to_string();
 
-    let meta_filename = base_path + &target + ".info.json";
+    let meta_filename = base_path.clone() + &target + ".info.json";
     let path = Path::new(&meta_filename);
 
     let mut meta_file = match File::create(&path) {
@@ -170,5 +278,10 @@ pub fn rename_file(req: &mut Request) -> IronResult<Response> {
         Ok(_) => (),
     }
 
+    match rename_stats(&base_path, file, &target) {
+        Err(why) => println!("Failed to move stats for {}: {}", file, why),
+        Ok(_) => (),
+    }
+
     Ok(Response::with((status::Ok, "Renamed")))
 }
@@ -0,0 +1,324 @@
+//! Contains the oEmbed endpoint, which describes uploads so that other sites can embed them.
+
+use config::ConfigContainer;
+
+use types::is_valid_name;
+use types::FileMetadata;
+use types::FileType;
+use types::StringError;
+
+use caching::cached_response;
+use caching::ContentClass;
+use caching::Validators;
+
+use compression::Compressible;
+
+use sniff::image_dimensions;
+
+use std::path::Path;
+
+use iron::mime::{Attr, Mime, SubLevel, TopLevel, Value as MimeValue};
+use iron::prelude::*;
+use iron::status;
+use iron::url::Url;
+
+use params::Map;
+use params::Params;
+use params::Value;
+
+use persistent;
+
+use serde_json;
+
+/// The size of embedded pages, such as pastes, when the consumer doesn't limit it.
+const RICH_SIZE: (u32, u32) = (640, 480);
+
+/// The size of embedded videos, when their dimensions aren't known.
+const VIDEO_SIZE: (u32, u32) = (640, 360);
+
+/// A response to an oEmbed request, as described by https://oembed.com/.
+#[derive(Serialize)]
+struct OEmbedResponse {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    version: &'static str,
+    title: String,
+    provider_name: &'static str,
+    provider_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    width: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    height: Option<u32>,
+}
+
+/// Links to the oEmbed endpoint for a page, used for discovery in templates.
+#[derive(Serialize)]
+pub struct OEmbedLinks {
+    json: String,
+    xml: String,
+}
+
+/// Builds the oEmbed discovery links for the page at the specified URL.
+pub fn discovery_links(base_url: &str, url: &str) -> OEmbedLinks {
+    let link = |format: &str| match Url::parse(&format!("{}oembed", base_url)) {
+        Ok(mut link) => {
+            link.query_pairs_mut()
+                .append_pair("url", url)
+                .append_pair("format", format);
+            link.to_string()
+        }
+        Err(_) => String::new(),
+    };
+
+    OEmbedLinks {
+        json: link("json"),
+        xml: link("xml"),
+    }
+}
+
+/// Escapes text for use within XML or HTML, including within attributes.
+fn escape(text: &str) -> String {
+    text.replace("&", "&amp;")
+        .replace("<", "&lt;")
+        .replace(">", "&gt;")
+        .replace("\"", "&quot;")
+        .replace("'", "&#39;")
+}
+
+/// Finds the name of the upload which a URL refers to, if it is a URL of this server.
+fn resolve_name(base_url: &str, target: &str) -> Option<String> {
+    if !target.starts_with(base_url) {
+        return None;
+    }
+
+    let path = target[base_url.len()..]
+        .split(|x| x == '?' || x == '#')
+        .next()
+        .unwrap_or("");
+
+    // Raw and download links refer to the same upload
+    let name = if path.starts_with("raw/") {
+        &path[4..]
+    } else if path.starts_with("dl/") {
+        &path[3..]
+    } else {
+        path
+    };
+
+    if is_valid_name(name) {
+        Some(name.to_string())
+    } else {
+        None
+    }
+}
+
+/// Reads an optional maxwidth or maxheight parameter.
+fn dimension_param(map: &Map, key: &str) -> Option<u32> {
+    match map.get(key) {
+        Some(&Value::String(ref value)) => value.parse().ok(),
+        Some(&Value::U64(value)) => Some(value as u32),
+        _ => None,
+    }
+}
+
+/// Scales some dimensions down to fit within the maximum size requested by the consumer,
+/// keeping their aspect ratio.
+fn fit(size: (u32, u32), max_width: Option<u32>, max_height: Option<u32>) -> (u32, u32) {
+    let (width, height) = size;
+    let mut scale = 1f64;
+
+    match max_width {
+        Some(max) if width > max => scale = scale.min(max as f64 / width as f64),
+        _ => (),
+    }
+
+    match max_height {
+        Some(max) if height > max => scale = scale.min(max as f64 / height as f64),
+        _ => (),
+    }
+
+    (
+        ((width as f64 * scale).round() as u32).max(1),
+        ((height as f64 * scale).round() as u32).max(1),
+    )
+}
+
+/// Serializes a response as XML, with an element for each of its fields.
+fn to_xml(response: &OEmbedResponse) -> Result<String, serde_json::Error> {
+    let mut output = String::new();
+    output += "<?xml version=\"1.0\" encoding=\"utf-8\" standalone=\"yes\"?>\n";
+    output += "<oembed>\n";
+
+    match serde_json::to_value(response)? {
+        serde_json::Value::Object(fields) => {
+            for (key, value) in fields {
+                let value = match value {
+                    serde_json::Value::String(value) => value,
+                    value => value.to_string(),
+                };
+
+                output += &format!("    <{}>{}</{}>\n", key, escape(&value), key);
+            }
+        }
+        _ => (),
+    }
+
+    output += "</oembed>\n";
+    Ok(output)
+}
+
+/// oEmbed endpoint. Describes how an upload can be embedded, given its URL.
+///
+/// HTTP request required state:
+///     Request kind: GET, with a key-value pair of url, and optionally format (json or xml),
+///                   maxwidth and maxheight
+///     Headers: None
+pub fn oembed(req: &mut Request) -> IronResult<Response> {
+    let (base_path, base_url) = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        (config.base_path.to_owned(), config.external_url.to_owned())
+    };
+
+    let (target, format, max_width, max_height) = {
+        let map = req.get_ref::<Params>().expect("No Params object available");
+
+        let target = extract_param_type!(map, String, "url")?.to_owned();
+        let format = match map.get("format") {
+            Some(&Value::String(ref format)) => format.to_owned(),
+            _ => "json".to_string(),
+        };
+
+        (
+            target,
+            format,
+            dimension_param(map, "maxwidth"),
+            dimension_param(map, "maxheight"),
+        )
+    };
+
+    if format != "json" && format != "xml" {
+        return Ok(Response::with(status::NotImplemented));
+    }
+
+    let name = match resolve_name(&base_url, &target) {
+        Some(name) => name,
+        None => return Ok(Response::with(status::NotFound)),
+    };
+
+    let meta = match FileMetadata::from_path(&base_path, &name) {
+        Ok(ref meta) if meta.is_expired() => return Ok(Response::with(status::NotFound)),
+        Ok(meta) => meta,
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    };
+
+    // Embedding private uploads would give away their contents
+    if meta.password.is_some() || meta.max_views.is_some() {
+        return Ok(Response::with(status::Unauthorized));
+    }
+
+    let page_url = format!("{}{}", base_url, name);
+    let raw_url = format!("{}raw/{}", base_url, name);
+
+    let mut response = OEmbedResponse {
+        kind: "link",
+        version: "1.0",
+        title: meta.filename.clone().unwrap_or_else(|| name.to_owned()),
+        provider_name: "Liftpush",
+        provider_url: base_url.to_owned(),
+        url: None,
+        html: None,
+        width: None,
+        height: None,
+    };
+
+    let size = match (meta.width, meta.height) {
+        (Some(width), Some(height)) => Some((width, height)),
+        _ => None,
+    };
+
+    match meta.file_type {
+        FileType::File => match meta.content_type() {
+            Mime(TopLevel::Image, _, _) => {
+                // Uploads from before dimensions were recorded are read when requested
+                let size = size.or_else(|| {
+                    let path = Path::new(&base_path).join(meta.actual_filename.clone()?);
+                    image_dimensions(&path)
+                });
+
+                match size {
+                    Some(size) => {
+                        let (width, height) = fit(size, max_width, max_height);
+                        response.kind = "photo";
+                        response.url = Some(raw_url);
+                        response.width = Some(width);
+                        response.height = Some(height);
+                    }
+                    None => (),
+                }
+            }
+            Mime(TopLevel::Video, _, _) => {
+                let (width, height) = fit(size.unwrap_or(VIDEO_SIZE), max_width, max_height);
+                response.kind = "video";
+                response.html = Some(format!(
+                    "<video src=\"{}\" width=\"{}\" height=\"{}\" controls></video>",
+                    escape(&raw_url),
+                    width,
+                    height
+                ));
+                response.width = Some(width);
+                response.height = Some(height);
+            }
+            _ => (),
+        },
+        FileType::Text | FileType::Collection => {
+            let (width, height) = fit(RICH_SIZE, max_width, max_height);
+            response.kind = "rich";
+            response.html = Some(format!(
+                "<iframe src=\"{}\" width=\"{}\" height=\"{}\" frameborder=\"0\"></iframe>",
+                escape(&page_url),
+                width,
+                height
+            ));
+            response.width = Some(width);
+            response.height = Some(height);
+        }
+        FileType::Url => (),
+    }
+
+    let (body, content_type) = if format == "xml" {
+        (
+            to_xml(&response),
+            Mime(
+                TopLevel::Text,
+                SubLevel::Xml,
+                vec![(Attr::Charset, MimeValue::Utf8)],
+            ),
+        )
+    } else {
+        (
+            serde_json::to_string(&response),
+            Mime(TopLevel::Application, SubLevel::Json, Vec::new()),
+        )
+    };
+
+    let body = body.map_err(|x| {
+        IronError::new(
+            x,
+            (status::InternalServerError, "Failed to serialize response"),
+        )
+    })?;
+
+    cached_response(req, &Validators::none(), ContentClass::Page, |_| {
+        Ok(Response::with((
+            content_type,
+            status::Ok,
+            body,
+            Compressible,
+        )))
+    })
+}
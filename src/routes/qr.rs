@@ -0,0 +1,118 @@
+//! Contains the QR code endpoint, so that links to uploads can be opened on a phone without
+//! typing them out.
+
+use config::ConfigContainer;
+
+use types::is_valid_name;
+use types::FileMetadata;
+
+use caching::cached_response;
+use caching::ContentClass;
+use caching::Validators;
+
+use compression::Compressible;
+
+use std::io::Cursor;
+
+use iron::mime::{Mime, SubLevel, TopLevel};
+use iron::prelude::*;
+use iron::status;
+
+use router::Router;
+
+use params::Params;
+use params::Value;
+
+use persistent;
+
+use image::DynamicImage;
+use image::ImageFormat;
+use image::Luma;
+
+use qrcode::render::svg;
+use qrcode::QrCode;
+
+/// The smallest width and height (in pixels) of rendered codes.
+const MIN_SIZE: u32 = 256;
+
+/// QR code endpoint. Renders a QR code for the link to an upload.
+///
+/// HTTP request required state:
+///     Request kind: GET, with the name of the upload as the last part of the URL, and
+///                   optionally a key-value pair of format (svg or png)
+///     Headers: None
+pub fn qr_code(req: &mut Request) -> IronResult<Response> {
+    let (base_path, base_url) = {
+        let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+        let config = arc.as_ref();
+        (config.base_path.to_owned(), config.external_url.to_owned())
+    };
+
+    let name = req
+        .extensions
+        .get::<Router>()
+        .unwrap()
+        .find("name")
+        .unwrap_or("")
+        .to_owned();
+
+    let format = {
+        let map = req.get_ref::<Params>().expect("No Params object available");
+        match map.get("format") {
+            Some(&Value::String(ref format)) => format.to_owned(),
+            _ => "svg".to_string(),
+        }
+    };
+
+    if format != "svg" && format != "png" {
+        return Ok(Response::with((status::BadRequest, "Unknown format")));
+    }
+
+    if !is_valid_name(&name) {
+        return Ok(Response::with(status::NotFound));
+    }
+
+    match FileMetadata::from_path(&base_path, &name) {
+        Ok(ref meta) if meta.is_expired() => return Ok(Response::with(status::NotFound)),
+        Ok(_) => (),
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    }
+
+    let code = QrCode::new(format!("{}{}", base_url, name))
+        .map_err(|x| IronError::new(x, (status::InternalServerError, "Unable to build QR code")))?;
+
+    // The code only depends on the link, so doesn't change while the upload exists
+    cached_response(req, &Validators::none(), ContentClass::Asset, |_| {
+        if format == "png" {
+            let image = code
+                .render::<Luma<u8>>()
+                .min_dimensions(MIN_SIZE, MIN_SIZE)
+                .build();
+
+            let mut output = Cursor::new(Vec::new());
+            DynamicImage::ImageLuma8(image)
+                .write_to(&mut output, ImageFormat::Png)
+                .map_err(|x| {
+                    IronError::new(x, (status::InternalServerError, "Unable to encode QR code"))
+                })?;
+
+            Ok(Response::with((
+                status::Ok,
+                Mime(TopLevel::Image, SubLevel::Png, Vec::new()),
+                output.into_inner(),
+            )))
+        } else {
+            let image = code
+                .render::<svg::Color>()
+                .min_dimensions(MIN_SIZE, MIN_SIZE)
+                .build();
+
+            Ok(Response::with((
+                status::Ok,
+                Mime(TopLevel::Image, SubLevel::Ext("svg+xml".into()), Vec::new()),
+                image,
+                Compressible,
+            )))
+        }
+    })
+}
@@ -1,24 +1,48 @@
 //! Hosts the upload API endpoint.
 
+use config::APIKey;
 use config::ConfigContainer;
 
+use types::is_reserved_name;
+use types::is_valid_name;
 use types::FileMetadata;
 use types::FileType;
+use types::ReservedName;
 use types::StringError;
 
 use rng::RandomFilename;
 
-use std::fs::copy;
+use expiry::parse_expiry;
+
+use auth::api_key_id;
+use auth::generate_token;
+use auth::hash_secret_salted;
+
+use blobs;
+
+use usage;
+
+use sniff::detect_type;
+use sniff::filename_extension;
+
+use std::env;
+use std::fs;
 use std::fs::File;
+use std::io;
 use std::io::Read;
 use std::io::Write;
 use std::path::Path;
 
+use iron::headers::ContentType;
 use iron::mime::{Mime, SubLevel, TopLevel};
 use iron::prelude::*;
 use iron::status;
 use iron::Error;
+use iron::Url;
 
+use bodyparser;
+
+use params;
 use params::Params;
 use params::Value;
 
@@ -28,17 +52,318 @@ use router::Router;
 
 use serde_json;
 
+use mime_guess;
+
+use rand;
+use rand::Rng;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::FixedOffset;
+use chrono::Local;
+
 /// Used to build the JSON payload as a response to an upload.
 #[derive(Serialize)]
 struct UploadStatus {
     url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    files: Option<Vec<String>>,
+    delete_url: String,
+}
+
+/// How many generated names are tried before an upload is given up on.
+const NAME_ATTEMPTS: usize = 10;
+
+/// The longest language name which can be stored with a text upload.
+const MAX_LANGUAGE_LENGTH: usize = 32;
+
+/// A request body which has been streamed to a temporary file, for uploads which aren't
+/// multipart forms. The temporary file is removed once this is dropped.
+struct RawBody {
+    file: params::File,
+}
+
+impl RawBody {
+    /// Streams the body of a request to a temporary file, failing if it is larger than the
+    /// specified maximum size.
+    fn stream(
+        req: &mut Request,
+        input_type: &FileType,
+        content_type: Mime,
+        max_size: Option<u64>,
+    ) -> IronResult<RawBody> {
+        let filename = match req
+            .extensions
+            .get::<Router>()
+            .and_then(|router| router.find("filename"))
+        {
+            Some(filename) => filename.to_owned(),
+            None => {
+                let ext = match (input_type, &content_type) {
+                    (&FileType::Text, _) => "txt",
+                    // curl sends this by default, so it doesn't describe the contents
+                    (_, &Mime(TopLevel::Application, SubLevel::WwwFormUrlEncoded, _)) => "bin",
+                    (_, content_type) => mime_guess::get_mime_extensions(content_type)
+                        .and_then(|exts| exts.first())
+                        .map(|ext| *ext)
+                        .unwrap_or("bin"),
+                };
+
+                format!("upload.{}", ext)
+            }
+        };
+
+        let mut body = RawBody {
+            file: params::File {
+                path: env::temp_dir().join(format!(
+                    "liftpush-{}.upload",
+                    rand::thread_rng().gen::<u64>()
+                )),
+                filename: Some(filename),
+                size: 0,
+                content_type,
+            },
+        };
+
+        let mut target = File::create(&body.file.path)
+            .map_err(|x| IronError::new(x, (status::InternalServerError, "Internal I/O error")))?;
+
+        // Read one byte past the limit, so that oversized bodies are caught without reading
+        // all of them
+        let limit = max_size.map(|x| x + 1).unwrap_or(u64::MAX);
+
+        body.file.size = io::copy(&mut req.body.by_ref().take(limit), &mut target)
+            .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?;
+
+        match max_size {
+            Some(max_size) if body.file.size > max_size => {
+                return Err(limit_error(
+                    status::PayloadTooLarge,
+                    format!("Upload exceeds the maximum size of {} bytes", max_size),
+                ))
+            }
+            _ => (),
+        }
+
+        Ok(body)
+    }
+}
+
+impl Drop for RawBody {
+    fn drop(&mut self) {
+        match fs::remove_file(&self.file.path) {
+            Err(e) => eprintln!("Failed to remove {:?}: {}", self.file.path, e),
+            _ => {}
+        }
+    }
+}
+
+/// Options which are applied to every file created by an upload.
+struct UploadOptions {
+    uploader: String,
+    expiry: Option<Duration>,
+    max_views: Option<u64>,
+    password: Option<String>,
+    language: Option<String>,
+    interstitial: bool,
+    delete_token: String,
+}
+
+/// Checks if a requested language is a plausible language name or extension (e.g. "c++").
+fn is_valid_language(language: &str) -> bool {
+    !language.is_empty()
+        && language.len() <= MAX_LANGUAGE_LENGTH
+        && language
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || "+#-_. ".contains(x))
+}
+
+/// Validates that the specified string is a URL that can be redirected to, returning the
+/// normalised form of the URL.
+fn parse_target_url(input: &str) -> Option<String> {
+    let input = input.trim();
+
+    if input.is_empty() || input.contains(char::is_whitespace) {
+        return None;
+    }
+
+    match Url::parse(input) {
+        Ok(url) => match url.scheme() {
+            "http" | "https" => Some(url.to_string()),
+            _ => None,
+        },
+        Err(_) => None,
+    }
+}
+
+/// Used to build the JSON payload when an upload is rejected for exceeding a limit.
+#[derive(Serialize)]
+struct LimitError {
+    error: String,
+}
+
+/// Builds an error for an upload which exceeds one of its API key's limits.
+fn limit_error(status: status::Status, message: String) -> IronError {
+    let response = serde_json::to_string(&LimitError {
+        error: message.clone(),
+    }).unwrap_or_else(|_| "{}".to_string());
+
+    IronError::new(
+        StringError(message),
+        (
+            status,
+            response,
+            Mime(TopLevel::Application, SubLevel::Json, Vec::new()),
+        ),
+    )
+}
+
+/// Claims storage for a new upload made with the specified API key, and counts it towards
+/// the key's uploads for the day. Fails if the key doesn't have enough storage left, or if
+/// the upload (and the specified number of uploads reserved to follow it, such as the
+/// collection gathering it with other files) would exceed the day's uploads.
+fn claim_usage(
+    base_path: &str,
+    api_key: &APIKey,
+    uploader: &str,
+    bytes: u64,
+    reserved: u64,
+) -> IronResult<DateTime<FixedOffset>> {
+    usage::update(base_path, uploader, |usage| {
+        match api_key.max_uploads_per_day {
+            Some(max_uploads) if usage.uploads.len() as u64 + 1 + reserved > max_uploads => {
+                return Err(limit_error(
+                    status::TooManyRequests,
+                    format!("Upload limit of {} per day reached", max_uploads),
+                ))
+            }
+            _ => (),
+        }
+
+        match api_key.max_total_size {
+            Some(max_size) if usage.stored_bytes + bytes > max_size => {
+                return Err(limit_error(
+                    status::PayloadTooLarge,
+                    format!("Upload exceeds the storage quota of {} bytes", max_size),
+                ))
+            }
+            _ => (),
+        }
+
+        let time = Local::now().with_timezone(&FixedOffset::east(0));
+        usage.stored_bytes += bytes;
+        usage.uploads.push(time);
+        Ok(time)
+    })
+}
+
+/// Gives back storage claimed for an upload which wasn't stored, along with its place in the
+/// day's uploads if specified.
+fn refund_usage(base_path: &str, uploader: &str, bytes: u64, time: Option<DateTime<FixedOffset>>) {
+    let result = usage::update(base_path, uploader, |usage| {
+        usage.stored_bytes = usage.stored_bytes.saturating_sub(bytes);
+
+        match time.and_then(|time| usage.uploads.iter().position(|x| *x == time)) {
+            Some(index) => {
+                usage.uploads.remove(index);
+            }
+            None => (),
+        }
+
+        Ok(())
+    });
+
+    match result {
+        Err(e) => eprintln!("Failed to refund usage of {:?}: {}", uploader, e),
+        _ => (),
+    }
+}
+
+/// Stores an upload of the specified size, claiming its usage first so that concurrent
+/// uploads can't exceed the API key's limits. The usage is given back if storing fails.
+fn store_with_usage<F>(
+    base_path: &str,
+    api_key: &APIKey,
+    uploader: &str,
+    bytes: u64,
+    reserved: u64,
+    store: F,
+) -> IronResult<FileMetadata>
+where
+    F: FnOnce() -> IronResult<FileMetadata>,
+{
+    let time = claim_usage(base_path, api_key, uploader, bytes, reserved)?;
+
+    match store() {
+        Ok(meta) => {
+            // Text uploads which turn out to be short links store nothing
+            let stored = meta.size.unwrap_or(0);
+            if stored < bytes {
+                refund_usage(base_path, uploader, bytes - stored, None);
+            }
+
+            Ok(meta)
+        }
+        Err(e) => {
+            refund_usage(base_path, uploader, bytes, Some(time));
+            Err(e)
+        }
+    }
+}
+
+/// Checks if the body of a request is the contents of the upload itself, rather than a
+/// multipart form. If so, the content type is taken out of the request, so that the body is
+/// left alone when form fields are looked up, and returned.
+fn take_raw_content_type(req: &mut Request) -> Option<Mime> {
+    let content_type = match req.headers.get::<ContentType>() {
+        Some(&ContentType(Mime(TopLevel::Multipart, SubLevel::FormData, _))) => return None,
+        Some(&ContentType(ref content_type)) => content_type.clone(),
+        None => Mime(TopLevel::Application, SubLevel::OctetStream, Vec::new()),
+    };
+
+    req.headers.remove::<ContentType>();
+    Some(content_type)
+}
+
+/// Finds an option requested for an upload, either as the specified header or form field.
+fn requested_option(req: &mut Request, header: &str, field: &str) -> IronResult<Option<String>> {
+    match req.headers.get_raw(header) {
+        Some(raw_value) => {
+            return String::from_utf8(raw_value[0].to_owned())
+                .map(Some)
+                .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")));
+        }
+        None => (),
+    }
+
+    let map = req.get_ref::<Params>().expect("No Params object available");
+
+    let param: IronResult<&str> = extract_param_type!(map, String, field);
+    Ok(param.map(|x| Some(x.to_string())).unwrap_or_else(|_x| None))
 }
 
 /// Upload endpoint. Uploads a specified file for a user.
 ///
 /// HTTP request required state:
-///     Request kind: POST, with a embedded file as "pushfile"
-///     Headers: required X-API-Key
+///     Request kind: POST, with a embedded file as "pushfile" (or several as "pushfile[]",
+///                   creating a collection of them), or for the url type, a "url" form
+///                   field or a raw body containing the target URL. Files can instead be
+///                   sent as the raw body of a POST (with a Content-Type), or of a PUT to
+///                   /upload/:type/:filename. All types accept an optional "name" field
+///                   specifying the upload's name, with an optional "name_fallback" field
+///                   ("true" to use a generated name if it is taken, rather than failing),
+///                   an optional "expires" field (e.g. "7d", or "never"), an optional
+///                   "max_views" field (e.g. 1 to remove the file after it is read), and an
+///                   optional "password" field required to view the file. Text uploads
+///                   accept an optional "language" field naming the language they are
+///                   highlighted as (e.g. "rust"), and short links accept an optional
+///                   "interstitial" field ("true" to always show the destination before
+///                   redirecting to it). Raw uploads take these from the query string instead
+///     Headers: required X-API-Key, optional X-Name, optional X-Name-Fallback, optional
+///              X-Expires, optional X-Max-Views, optional X-Password, optional X-Language,
+///              optional X-Interstitial
 pub fn upload(req: &mut Request) -> IronResult<Response> {
     let base_path = {
         let arc = req
@@ -61,36 +386,36 @@ pub fn upload(req: &mut Request) -> IronResult<Response> {
             .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?
     };
 
-    {
+    let api_key = {
         let arc = req
             .get::<persistent::Read<ConfigContainer>>()
             .expect("No ConfigContainer object available");
         let config = arc.as_ref();
 
         // Find target user
-        let mut found = false;
+        let mut found = None;
 
         for key in &config.api_keys {
             if key.key == api_key {
-                found = true;
+                found = Some(key.clone());
             }
         }
 
-        if !found {
-            return Err(IronError::new(
-                StringError("Bad API key in submitted form".into()),
-                (status::BadRequest, "Bad API key"),
-            ));
+        match found {
+            Some(key) => key,
+            None => {
+                return Err(IronError::new(
+                    StringError("Bad API key in submitted form".into()),
+                    (status::BadRequest, "Bad API key"),
+                ))
+            }
         }
-    }
-
-    // Fetch other request attributes
-    let file = {
-        let map = req.get_ref::<Params>().expect("No Params object available");
-
-        extract_param_type!(map, File, "pushfile")?.to_owned()
     };
 
+    // The key's limits are enforced as each file is stored
+    let uploader = api_key_id(&api_key.key);
+
+    // Fetch other request attributes
     let input_type = {
         req.extensions
             .get::<Router>()
@@ -115,7 +440,432 @@ pub fn upload(req: &mut Request) -> IronResult<Response> {
         }
     };
 
-    let out_file = RandomFilename::from(req)?;
+    // Short links read their body themselves, so only file uploads can have a raw body
+    let raw_content_type = match input_type {
+        FileType::Url => None,
+        _ => take_raw_content_type(req),
+    };
+
+    let (base_url, default_expiry) = {
+        let arc = req
+            .get::<persistent::Read<ConfigContainer>>()
+            .expect("Config file is missing");
+        let config = arc.as_ref();
+        (
+            config.external_url.to_owned(),
+            config.default_expiry.to_owned(),
+        )
+    };
+
+    // Work out how long this upload should live for before anything is stored
+    let expiry = match requested_option(req, "X-Expires", "expires")?.or(default_expiry) {
+        Some(expiry) => parse_expiry(&expiry)
+            .map_err(|x| IronError::new(StringError(x), (status::BadRequest, "Invalid expiry")))?,
+        None => None,
+    };
+
+    let max_views = match requested_option(req, "X-Max-Views", "max_views")? {
+        Some(max_views) => match max_views.trim().parse::<u64>() {
+            Ok(max_views) if max_views > 0 => Some(max_views),
+            _ => {
+                return Err(IronError::new(
+                    StringError(format!("Max views {:?} isn't a valid count", max_views)),
+                    (status::BadRequest, "Invalid max views"),
+                ))
+            }
+        },
+        None => None,
+    };
+
+    let password = match requested_option(req, "X-Password", "password")? {
+        Some(ref password) if password.is_empty() => {
+            return Err(IronError::new(
+                StringError("Empty password specified".into()),
+                (status::BadRequest, "Invalid password"),
+            ))
+        }
+        Some(password) => Some(hash_secret_salted(&password)),
+        None => None,
+    };
+
+    let language = match requested_option(req, "X-Language", "language")? {
+        Some(language) => {
+            if !is_valid_language(&language) {
+                return Err(IronError::new(
+                    StringError(format!("Language {:?} contains bad characters", language)),
+                    (status::BadRequest, "Invalid language"),
+                ));
+            }
+
+            Some(language)
+        }
+        None => None,
+    };
+
+    let requested_name = match requested_option(req, "X-Name", "name")? {
+        Some(name) => {
+            if !is_valid_name(&name) {
+                return Err(IronError::new(
+                    StringError(format!("Requested name {:?} contains bad characters", name)),
+                    (status::BadRequest, "Invalid name"),
+                ));
+            }
+
+            if is_reserved_name(&name) {
+                return Err(IronError::new(
+                    StringError(format!("Requested name {:?} is reserved", name)),
+                    (status::Conflict, "Name already in use"),
+                ));
+            }
+
+            Some(name)
+        }
+        None => None,
+    };
+
+    let name_fallback = match requested_option(req, "X-Name-Fallback", "name_fallback")? {
+        Some(fallback) => match fallback.trim() {
+            "true" | "1" => true,
+            "false" | "0" => false,
+            _ => {
+                return Err(IronError::new(
+                    StringError(format!("Name fallback {:?} isn't a boolean", fallback)),
+                    (status::BadRequest, "Invalid name fallback"),
+                ))
+            }
+        },
+        None => false,
+    };
+
+    let interstitial = match requested_option(req, "X-Interstitial", "interstitial")? {
+        Some(interstitial) => match interstitial.trim() {
+            "true" | "1" => true,
+            "false" | "0" => false,
+            _ => {
+                return Err(IronError::new(
+                    StringError(format!("Interstitial {:?} isn't a boolean", interstitial)),
+                    (status::BadRequest, "Invalid interstitial"),
+                ))
+            }
+        },
+        None => false,
+    };
+
+    // Everything created by this upload can be deleted with the same token
+    let delete_token = generate_token();
+
+    let options = UploadOptions {
+        uploader,
+        expiry,
+        max_views,
+        password,
+        language,
+        interstitial,
+        delete_token: hash_secret_salted(&delete_token),
+    };
+
+    let (url, meta, members) = match input_type {
+        // Short link creation
+        FileType::Url => {
+            let name = reserve_name(req, &base_path, requested_name, name_fallback)?;
+            let url = name.name.clone();
+
+            let meta = store_with_usage(&base_path, &api_key, &options.uploader, 0, 0, || {
+                let mut meta = upload_url(req, &url)?;
+                save_upload(name, &mut meta, &options)?;
+                Ok(meta)
+            })?;
+
+            (url, meta, None)
+        }
+        // Collections are only created from multiple files
+        FileType::Collection => {
+            return Err(IronError::new(
+                StringError("Collection uploaded directly".into()),
+                (status::BadRequest, "Invalid input type"),
+            ))
+        }
+        // General file upload/specific text file upload
+        _ => {
+            let raw_body;
+
+            let (files, is_collection) = match raw_content_type {
+                Some(content_type) => {
+                    raw_body =
+                        RawBody::stream(req, &input_type, content_type, api_key.max_upload_size)?;
+                    (vec![raw_body.file.clone()], false)
+                }
+                None => requested_files(req)?,
+            };
+
+            // Rendering the gallery would use up the views of every file within it
+            if is_collection && options.max_views.is_some() {
+                return Err(IronError::new(
+                    StringError("Max views specified for a collection".into()),
+                    (status::BadRequest, "Collections can't have max views"),
+                ));
+            }
+
+            // Uploads which won't fit in the day's limit are refused before anything is stored,
+            // though files are still counted one at a time in case of concurrent uploads
+            let count = files.len() as u64 + if is_collection { 1 } else { 0 };
+            let uploads_today = usage::uploads_today(&base_path, &options.uploader)?;
+
+            match api_key.max_uploads_per_day {
+                Some(max_uploads) if uploads_today + count > max_uploads => {
+                    return Err(limit_error(
+                        status::TooManyRequests,
+                        format!("Upload limit of {} per day reached", max_uploads),
+                    ))
+                }
+                _ => (),
+            }
+
+            // The requested name is claimed first, so nothing is stored if it is unavailable
+            let first_name = reserve_name(req, &base_path, requested_name, name_fallback)?;
+
+            let (collection_name, mut member_names) = if is_collection {
+                (Some(first_name), Vec::new())
+            } else {
+                (None, vec![first_name])
+            };
+
+            while member_names.len() < files.len() {
+                member_names.push(reserve_name(req, &base_path, None, true)?);
+            }
+
+            let mut uploaded = Vec::new();
+
+            // Room is left in the day's uploads for the collection, so that it can't be
+            // refused once its files are stored
+            let reserved = if collection_name.is_some() { 1 } else { 0 };
+
+            // Each file is saved as it is stored, so nothing is left without metadata. Files
+            // count towards the key's limits one at a time, so stop at the first one past them
+            for (file, name) in files.into_iter().zip(member_names) {
+                let url = name.name.clone();
+
+                let meta = store_with_usage(
+                    &base_path,
+                    &api_key,
+                    &options.uploader,
+                    file.size,
+                    reserved,
+                    || {
+                        let mut meta = upload_file(&base_path, &url, &input_type, file, &api_key)?;
+                        save_upload(name, &mut meta, &options)?;
+                        Ok(meta)
+                    },
+                )?;
+
+                uploaded.push((url, meta));
+            }
+
+            if let Some(name) = collection_name {
+                let members: Vec<String> = uploaded.iter().map(|x| x.0.clone()).collect();
+
+                let url = name.name.clone();
+                let meta = store_with_usage(&base_path, &api_key, &options.uploader, 0, 0, || {
+                    let mut meta = FileMetadata::new_from_collection(members.clone());
+                    save_upload(name, &mut meta, &options)?;
+                    Ok(meta)
+                })?;
+
+                println!("Created collection {:?} of {:?}", url, members);
+
+                (url, meta, Some(members))
+            } else {
+                let (url, meta) = uploaded.remove(0);
+                (url, meta, None)
+            }
+        }
+    };
+
+    let response = serde_json::to_string(&UploadStatus {
+        url: base_url.clone() + &url,
+        expires: meta.expires.map(|x| x.to_rfc2822()),
+        files: members.map(|members| {
+            members
+                .iter()
+                .map(|member| base_url.clone() + member)
+                .collect()
+        }),
+        delete_url: format!("{}delete/{}/{}", base_url, url, delete_token),
+    }).map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?;
+
+    Ok(Response::with((
+        status::Ok,
+        response,
+        Mime(TopLevel::Application, SubLevel::Json, Vec::new()),
+    )))
+}
+
+/// Handles the url type of upload, returning the metadata of the new short link.
+fn upload_url(req: &mut Request, name: &str) -> IronResult<FileMetadata> {
+    let target = {
+        let map = req.get_ref::<Params>().expect("No Params object available");
+
+        let target: IronResult<&str> = extract_param_type!(map, String, "url");
+        target
+            .map(|x| Some(x.to_string()))
+            .unwrap_or_else(|_x| None)
+    };
+
+    // Fall back to the raw body if no form field was specified
+    let target = match target {
+        Some(target) => target,
+        None => req
+            .get::<bodyparser::Raw>()
+            .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?
+            .ok_or_else(|| {
+                IronError::new(
+                    StringError("No target URL specified".into()),
+                    (status::BadRequest, "No target URL specified"),
+                )
+            })?,
+    };
+
+    let target = parse_target_url(&target).ok_or_else(|| {
+        IronError::new(
+            StringError(format!("Target URL {:?} is invalid", target)),
+            (status::BadRequest, "Invalid target URL"),
+        )
+    })?;
+
+    println!("Creating short link {:?} to {:?}", name, target);
+
+    Ok(FileMetadata::new_from_url(target))
+}
+
+/// Reserves the name of a new upload. If a name was requested but is already in use, either
+/// a generated name is used instead or the upload is rejected, depending on `fallback`.
+fn reserve_name(
+    req: &mut Request,
+    base_path: &str,
+    requested: Option<String>,
+    fallback: bool,
+) -> IronResult<ReservedName> {
+    let reserve = |name: &str| {
+        ReservedName::reserve(base_path, name).map_err(|x| {
+            IronError::new(
+                StringError(x),
+                (status::InternalServerError, "Internal I/O error"),
+            )
+        })
+    };
+
+    match requested {
+        Some(name) => {
+            if let Some(reserved) = reserve(&name)? {
+                return Ok(reserved);
+            }
+
+            if !fallback {
+                return Err(IronError::new(
+                    StringError(format!("Requested name {:?} already exists", name)),
+                    (status::Conflict, "Name already in use"),
+                ));
+            }
+        }
+        None => (),
+    }
+
+    // Generated names can collide with existing ones as well
+    for _ in 0..NAME_ATTEMPTS {
+        let name = RandomFilename::from(req)?.filename;
+
+        if let Some(reserved) = reserve(&name)? {
+            return Ok(reserved);
+        }
+    }
+
+    Err(IronError::new(
+        StringError("Ran out of attempts to generate an unused name".into()),
+        (status::InternalServerError, "Unable to generate a name"),
+    ))
+}
+
+/// Applies the options of an upload to a newly stored file, and saves its metadata over the
+/// reserved name.
+fn save_upload(
+    name: ReservedName,
+    meta: &mut FileMetadata,
+    options: &UploadOptions,
+) -> IronResult<()> {
+    meta.uploader = Some(options.uploader.clone());
+
+    meta.expires = options
+        .expiry
+        .and_then(|expiry| meta.date.checked_add_signed(expiry));
+    meta.max_views = options.max_views;
+    meta.views = options.max_views.map(|_| 0);
+    meta.password = options.password.clone();
+
+    match meta.file_type {
+        FileType::Text => meta.language = options.language.clone(),
+        FileType::Url if options.interstitial => meta.interstitial = Some(true),
+        _ => (),
+    }
+
+    meta.delete_token = Some(options.delete_token.clone());
+
+    name.save(meta).map_err(|x| {
+        println!("Couldn't save metadata: {}", x);
+        IronError::new(
+            StringError("Failed to write file".into()),
+            (status::InternalServerError, "Failed to write file"),
+        )
+    })
+}
+
+/// Extracts the files submitted with an upload. Multiple files can be submitted as
+/// "pushfile[]", in which case a collection should be created from them.
+fn requested_files(req: &mut Request) -> IronResult<(Vec<params::File>, bool)> {
+    let map = req.get_ref::<Params>().expect("No Params object available");
+
+    match map.get("pushfile") {
+        Some(&Value::File(ref file)) => return Ok((vec![file.to_owned()], false)),
+        Some(&Value::Array(ref values)) => {
+            let files: Vec<params::File> = values
+                .iter()
+                .filter_map(|value| match *value {
+                    Value::File(ref file) => Some(file.to_owned()),
+                    _ => None,
+                })
+                .collect();
+
+            if !files.is_empty() && files.len() == values.len() {
+                return Ok((files, true));
+            }
+        }
+        _ => (),
+    }
+
+    Err(IronError::new(
+        StringError("Unable to find pushfile in submitted form".into()),
+        (status::BadRequest, "Missing form params"),
+    ))
+}
+
+/// Handles the file and text types of upload, storing the file under the specified name and
+/// returning its metadata.
+fn upload_file(
+    base_path: &str,
+    name: &str,
+    input_type: &FileType,
+    file: params::File,
+    api_key: &APIKey,
+) -> IronResult<FileMetadata> {
+    // Enforce the key's size limits before anything is copied
+    match api_key.max_upload_size {
+        Some(max_size) if file.size > max_size => {
+            return Err(limit_error(
+                status::PayloadTooLarge,
+                format!("Upload exceeds the maximum size of {} bytes", max_size),
+            ))
+        }
+        _ => (),
+    }
 
     // Generate metadata properties
     let original_filename = match file.filename.clone() {
@@ -128,22 +878,23 @@ pub fn upload(req: &mut Request) -> IronResult<Response> {
         }
     };
 
-    let ext_split = original_filename.clone();
-    let ext: Option<&str> = ext_split.split(".").last();
-
-    let url = out_file.filename.clone();
+    // Files are stored with an extension matching their detected type
+    let detected = match *input_type {
+        FileType::File => Some(
+            detect_type(&file.path, &original_filename)
+                .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?,
+        ),
+        _ => None,
+    };
 
-    let new_filename = match ext {
-        Some(ext) => out_file.filename + "." + ext,
-        _ => out_file.filename,
+    let ext = match detected {
+        Some(ref detected) => detected.extension.clone(),
+        None => filename_extension(&original_filename),
     };
 
-    let base_url = {
-        let arc = req
-            .get::<persistent::Read<ConfigContainer>>()
-            .expect("Config file is missing");
-        let config = arc.as_ref();
-        config.external_url.to_owned()
+    let new_filename = match ext {
+        Some(ext) => name.to_string() + "." + &ext,
+        _ => name.to_string(),
     };
 
     println!(
@@ -152,7 +903,7 @@ pub fn upload(req: &mut Request) -> IronResult<Response> {
     );
 
     // Generate metadata
-    let meta = match input_type {
+    let meta = match *input_type {
         // General file upload
         FileType::File => {
             let target_file = Path::new(&base_path).join(&new_filename);
@@ -164,114 +915,75 @@ pub fn upload(req: &mut Request) -> IronResult<Response> {
                 ));
             }
 
-            copy(file.path, target_file)
+            let hash = blobs::store(&base_path, &file.path, &target_file)
                 .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?;
-            FileMetadata::new_from_file(original_filename, new_filename.clone())
+
+            let mut meta = FileMetadata::new_from_file(original_filename, new_filename.clone());
+            meta.hash = Some(hash);
+            meta.size = Some(file.size);
+            match detected {
+                Some(detected) => {
+                    meta.mime = Some(detected.mime);
+                    meta.width = detected.dimensions.map(|x| x.0);
+                    meta.height = detected.dimensions.map(|x| x.1);
+                }
+                None => (),
+            }
+            meta
         }
         // Specifc text file upload
-        FileType::Text => {
+        _ => {
             let mut data = Vec::new();
             file.open()
                 .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?
                 .read_to_end(&mut data)
                 .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?;
 
-            let mut data = String::from_utf8(data)
+            let data = String::from_utf8(data)
                 .map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?;
 
-            if data[0..8].contains("://") {
-                FileMetadata::new_from_url(data)
-            } else {
-                // Save buffered text
-                let meta_filename = base_path.to_string() + &new_filename;
-                let path = Path::new(&meta_filename);
-
-                let mut meta_file = match File::create(&path) {
-                    Err(why) => {
-                        println!("Couldn't create {}: {}", meta_filename, why.description());
-                        return Err(IronError::new(
-                            StringError("Failed to write file".into()),
-                            (status::InternalServerError, "Failed to write file"),
-                        ));
+            // Older clients upload short links as text
+            match parse_target_url(&data) {
+                Some(target) => FileMetadata::new_from_url(target),
+                None => {
+                    // Save buffered text
+                    let meta_filename = base_path.to_string() + &new_filename;
+                    let path = Path::new(&meta_filename);
+
+                    let mut meta_file = match File::create(&path) {
+                        Err(why) => {
+                            println!("Couldn't create {}: {}", meta_filename, why.description());
+                            return Err(IronError::new(
+                                StringError("Failed to write file".into()),
+                                (status::InternalServerError, "Failed to write file"),
+                            ));
+                        }
+                        Ok(file) => file,
+                    };
+
+                    match meta_file.write_all(data.as_bytes()) {
+                        Err(why) => {
+                            println!(
+                                "Failed to write to {}: {}",
+                                meta_filename,
+                                why.description()
+                            );
+                            return Err(IronError::new(
+                                StringError("Failed to write file".into()),
+                                (status::InternalServerError, "Failed to write file"),
+                            ));
+                        }
+                        Ok(_) => (),
                     }
-                    Ok(file) => file,
-                };
 
-                match meta_file.write_all(data.as_bytes()) {
-                    Err(why) => {
-                        println!(
-                            "Failed to write to {}: {}",
-                            meta_filename,
-                            why.description()
-                        );
-                        return Err(IronError::new(
-                            StringError("Failed to write file".into()),
-                            (status::InternalServerError, "Failed to write file"),
-                        ));
-                    }
-                    Ok(_) => (),
+                    let mut meta =
+                        FileMetadata::new_from_text(original_filename, new_filename.clone());
+                    meta.size = Some(data.len() as u64);
+                    meta
                 }
-
-                FileMetadata::new_from_text(original_filename, new_filename.clone())
             }
         }
-        _ => {
-            // URL
-            return Err(IronError::new(
-                StringError("URL uploading type not supported".into()),
-                (status::InternalServerError, "URL type not implemented"),
-            ));
-        }
-    };
-
-    let meta_string = match serde_json::to_string(&meta) {
-        Ok(data) => data,
-        Err(msg) => {
-            println!("Couldn't serialize metadata: {}", msg.description());
-            return Err(IronError::new(
-                StringError("Failed to generate metadata".into()),
-                (status::InternalServerError, "Failed to generate metadata"),
-            ));
-        }
-    };
-
-    // Save metadata
-    let meta_filename = base_path.to_string() + &url + ".info.json";
-    let path = Path::new(&meta_filename);
-
-    let mut meta_file = match File::create(&path) {
-        Err(why) => {
-            println!("Couldn't create {}: {}", meta_filename, why.description());
-            return Err(IronError::new(
-                StringError("Failed to write file".into()),
-                (status::InternalServerError, "Failed to write file"),
-            ));
-        }
-        Ok(file) => file,
     };
 
-    match meta_file.write_all(meta_string.as_bytes()) {
-        Err(why) => {
-            println!(
-                "Failed to write to {}: {}",
-                meta_filename,
-                why.description()
-            );
-            return Err(IronError::new(
-                StringError("Failed to write file".into()),
-                (status::InternalServerError, "Failed to write file"),
-            ));
-        }
-        Ok(_) => (),
-    }
-
-    let response = serde_json::to_string(&UploadStatus {
-        url: base_url + &url,
-    }).map_err(|x| IronError::new(x, (status::BadRequest, "Internal I/O error")))?;
-
-    Ok(Response::with((
-        status::Ok,
-        response,
-        Mime(TopLevel::Application, SubLevel::Json, Vec::new()),
-    )))
+    Ok(meta)
 }
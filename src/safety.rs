@@ -0,0 +1,173 @@
+//! Protects the management UI from uploads which browsers could run scripts from, such as
+//! HTML and SVG files, which would otherwise share its origin and session cookie.
+
+use iron::headers::DispositionType;
+use iron::mime::{Mime, SubLevel, TopLevel};
+use iron::prelude::*;
+use iron::status;
+use iron::BeforeMiddleware;
+use iron::Url;
+
+use persistent;
+
+use config::ActiveContentPolicy;
+use config::ConfigContainer;
+
+use types::StringError;
+
+/// Checks if browsers could run scripts from a type of content when it is shown inline.
+fn is_active_content(mime: &Mime) -> bool {
+    match *mime {
+        Mime(TopLevel::Text, SubLevel::Html, _) => true,
+        Mime(TopLevel::Text, SubLevel::Xml, _) => true,
+        Mime(TopLevel::Application, SubLevel::Xml, _) => true,
+        // e.g. image/svg+xml and application/xhtml+xml
+        Mime(_, SubLevel::Ext(ref sub), _) => sub.ends_with("+xml") || sub == "xsl",
+        _ => false,
+    }
+}
+
+/// Changes how an upload is served if browsers could run scripts from it.
+pub fn safe_content_type(
+    content_type: Mime,
+    disposition: DispositionType,
+    policy: ActiveContentPolicy,
+) -> (Mime, DispositionType) {
+    if !is_active_content(&content_type) {
+        return (content_type, disposition);
+    }
+
+    match policy {
+        ActiveContentPolicy::Attachment => (content_type, DispositionType::Attachment),
+        ActiveContentPolicy::Text => (
+            Mime(TopLevel::Text, SubLevel::Plain, Vec::new()),
+            disposition,
+        ),
+    }
+}
+
+/// Marks a response as containing an upload, so that browsers neither guess at its type nor
+/// run any scripts within it.
+pub fn protect(response: &mut Response) {
+    response
+        .headers
+        .set_raw("Content-Security-Policy", vec![b"sandbox".to_vec()]);
+    response
+        .headers
+        .set_raw("X-Content-Type-Options", vec![b"nosniff".to_vec()]);
+}
+
+/// Checks if a request was made to the host of a URL. Ports aren't compared, as they
+/// usually differ behind a reverse proxy.
+pub fn is_origin(req: &Request, url: &str) -> bool {
+    match Url::parse(url) {
+        Ok(url) => url.host() == req.url.host(),
+        Err(_) => false,
+    }
+}
+
+/// Builds a link to the path and query of a request, on the origin of another URL.
+pub fn on_origin(req: &Request, base_url: &str) -> String {
+    let mut link = base_url.to_string() + &req.url.path().join("/");
+
+    match req.url.query() {
+        Some(query) => {
+            link.push('?');
+            link.push_str(query);
+        }
+        None => (),
+    }
+
+    link
+}
+
+/// Middleware which refuses requests made to the user content origin, so that the management
+/// UI is only ever served from the main origin.
+pub struct MainOriginOnly;
+
+impl BeforeMiddleware for MainOriginOnly {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        let user_content_url = {
+            let arc = req.get::<persistent::Read<ConfigContainer>>().unwrap();
+            let config = arc.as_ref();
+            config.content_safety.user_content_url.to_owned()
+        };
+
+        match user_content_url {
+            Some(ref url) if is_origin(req, url) => Err(IronError::new(
+                StringError("Management requested on user content origin".to_string()),
+                (status::NotFound, "Not found"),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_active(mime: &str) -> bool {
+        is_active_content(&mime.parse::<Mime>().unwrap())
+    }
+
+    #[test]
+    fn markup_is_active() {
+        assert!(is_active("text/html"));
+        assert!(is_active("text/html; charset=utf-8"));
+        assert!(is_active("text/xml"));
+        assert!(is_active("application/xml"));
+        assert!(is_active("text/xsl"));
+    }
+
+    #[test]
+    fn xml_based_types_are_active() {
+        assert!(is_active("image/svg+xml"));
+        assert!(is_active("application/xhtml+xml"));
+        assert!(is_active("application/rss+xml"));
+    }
+
+    #[test]
+    fn other_types_are_inactive() {
+        assert!(!is_active("text/plain"));
+        assert!(!is_active("text/css"));
+        assert!(!is_active("image/png"));
+        assert!(!is_active("application/json"));
+        assert!(!is_active("application/octet-stream"));
+        assert!(!is_active("video/mp4"));
+    }
+
+    #[test]
+    fn active_content_is_made_safe() {
+        let html = "text/html".parse::<Mime>().unwrap();
+
+        let (mime, disposition) = safe_content_type(
+            html.clone(),
+            DispositionType::Inline,
+            ActiveContentPolicy::Attachment,
+        );
+        assert_eq!(mime, html);
+        assert_eq!(disposition, DispositionType::Attachment);
+
+        let (mime, disposition) = safe_content_type(
+            html.clone(),
+            DispositionType::Inline,
+            ActiveContentPolicy::Text,
+        );
+        assert_eq!(mime, "text/plain".parse::<Mime>().unwrap());
+        assert_eq!(disposition, DispositionType::Inline);
+    }
+
+    #[test]
+    fn inactive_content_is_unchanged() {
+        let png = "image/png".parse::<Mime>().unwrap();
+
+        let (mime, disposition) = safe_content_type(
+            png.clone(),
+            DispositionType::Inline,
+            ActiveContentPolicy::Text,
+        );
+        assert_eq!(mime, png);
+        assert_eq!(disposition, DispositionType::Inline);
+    }
+}
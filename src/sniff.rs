@@ -0,0 +1,128 @@
+//! Detects the content type of uploads from their contents, rather than trusting the
+//! extension of the filename they were uploaded with.
+
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::str;
+
+use iron::mime::{Mime, SubLevel, TopLevel};
+
+use infer::Infer;
+
+use image::ImageReader;
+
+use mime_guess;
+
+/// How many bytes from the start of a file are used to detect its type.
+const SNIFF_LENGTH: u64 = 8192;
+
+/// The longest extension which is kept from an uploaded filename.
+const MAX_EXTENSION_LENGTH: usize = 16;
+
+/// The type of a file, as detected from its contents.
+pub struct DetectedType {
+    pub mime: String,
+    pub extension: Option<String>,
+    /// The width and height of images, read from their headers.
+    pub dimensions: Option<(u32, u32)>,
+}
+
+/// Returns the extension of an uploaded filename, if it has a usable one.
+pub fn filename_extension(filename: &str) -> Option<String> {
+    if !filename.contains(".") {
+        return None;
+    }
+
+    match filename.split(".").last() {
+        Some(ext)
+            if !ext.is_empty()
+                && ext.len() <= MAX_EXTENSION_LENGTH
+                && ext.chars().all(|x| x.is_ascii_alphanumeric()) =>
+        {
+            Some(ext.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// Checks if a type guessed from an extension is suitable for textual contents.
+fn is_textual(mime: &Mime) -> bool {
+    match *mime {
+        Mime(TopLevel::Text, _, _) => true,
+        Mime(TopLevel::Application, SubLevel::Json, _) => true,
+        Mime(TopLevel::Application, SubLevel::Javascript, _) => true,
+        Mime(TopLevel::Application, SubLevel::Xml, _) => true,
+        Mime(TopLevel::Image, SubLevel::Ext(ref sub), _) => sub == "svg+xml",
+        _ => false,
+    }
+}
+
+/// Reads the dimensions of an image from its header. Uploads are stored in temporary files
+/// without an extension, so the format has to be guessed from the contents.
+pub fn image_dimensions(path: &Path) -> Option<(u32, u32)> {
+    match ImageReader::open(path).and_then(|reader| reader.with_guessed_format()) {
+        Ok(reader) => reader.into_dimensions().ok(),
+        Err(_) => None,
+    }
+}
+
+/// Detects the type of a file from its first few bytes. Binary formats are recognised by their
+/// signature, and the stored extension is chosen to match. Plain text can't be told apart
+/// this way, so the extension of the original filename is used to pick a textual type.
+pub fn detect_type(path: &Path, filename: &str) -> io::Result<DetectedType> {
+    let mut buffer = Vec::new();
+    File::open(path)?
+        .take(SNIFF_LENGTH)
+        .read_to_end(&mut buffer)?;
+
+    let original_ext = filename_extension(filename);
+    let guessed = original_ext
+        .as_ref()
+        .and_then(|ext| mime_guess::get_mime_type_opt(ext));
+
+    match Infer::new().get(&buffer) {
+        Some(detected) => {
+            // Keep the original extension if it is just another name for the same type
+            let extension = match (guessed, original_ext) {
+                (Some(ref guessed), Some(original_ext)) if guessed.to_string() == detected.mime => {
+                    original_ext
+                }
+                _ => detected.ext,
+            };
+
+            let dimensions = if detected.mime.starts_with("image/") {
+                image_dimensions(path)
+            } else {
+                None
+            };
+
+            return Ok(DetectedType {
+                mime: detected.mime,
+                extension: Some(extension),
+                dimensions,
+            });
+        }
+        None => (),
+    }
+
+    // The buffer may end partway through a character, which is still valid text
+    let is_text = !buffer.contains(&0)
+        && match str::from_utf8(&buffer) {
+            Ok(_) => true,
+            Err(e) => e.error_len().is_none(),
+        };
+
+    let mime = match guessed {
+        Some(ref guessed) if is_text && is_textual(guessed) => guessed.to_string(),
+        _ if is_text => "text/plain; charset=utf-8".to_string(),
+        _ => "application/octet-stream".to_string(),
+    };
+
+    Ok(DetectedType {
+        mime,
+        extension: original_ext,
+        dimensions: None,
+    })
+}
@@ -0,0 +1,447 @@
+//! Records how often uploads are accessed, and by what. Hits are collected by a background
+//! thread, which periodically saves them next to each upload's metadata, so that serving
+//! uploads isn't slowed down by writing them. Renames and removals of uploads are sent to the
+//! same thread, so that hits which haven't been saved yet follow the upload.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::sync::mpsc;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::mpsc::Sender;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use chrono::DateTime;
+use chrono::FixedOffset;
+use chrono::Local;
+
+use iron::headers::Referer;
+use iron::headers::UserAgent;
+use iron::prelude::*;
+use iron::typemap::Key;
+use iron::Url;
+
+use serde_json;
+
+/// How often collected hits are saved to disk.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// The most referrers which are kept for each upload. Past this, the least common are dropped.
+const MAX_REFERRERS: usize = 20;
+
+/// Parts of the User-Agent of crawlers and link preview services.
+const BOT_MARKERS: &[&str] = &[
+    "bot",
+    "crawler",
+    "spider",
+    "facebookexternalhit",
+    "whatsapp",
+    "skypeuripreview",
+    "mattermost",
+    "embedly",
+];
+
+/// Parts of the User-Agent of command line tools and HTTP libraries.
+const CLI_MARKERS: &[&str] = &[
+    "curl/",
+    "wget/",
+    "httpie/",
+    "python-",
+    "python/",
+    "go-http-client/",
+    "okhttp/",
+    "java/",
+    "powershell/",
+    "libwww-perl/",
+];
+
+/// Parts of the User-Agent of mobile browsers.
+const MOBILE_MARKERS: &[&str] = &["mobile", "android", "iphone", "ipad"];
+
+/// How often an upload has been accessed, and by what.
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct AccessStats {
+    pub hits: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_access: Option<DateTime<FixedOffset>>,
+    /// Hits for each host which linked to the upload.
+    #[serde(default)]
+    pub referrers: BTreeMap<String, u64>,
+    /// Hits for each class of client, e.g. "desktop" or "bot".
+    #[serde(default)]
+    pub agents: BTreeMap<String, u64>,
+}
+
+/// A single access of an upload.
+pub struct Hit {
+    name: String,
+    time: DateTime<FixedOffset>,
+    referrer: Option<String>,
+    agent: &'static str,
+}
+
+/// A change to the stats of an upload, sent to the background thread which saves them.
+enum Update {
+    /// The upload was accessed.
+    Hit(Hit),
+    /// The upload was renamed from the first name to the second.
+    Rename(String, String),
+    /// The upload was removed.
+    Remove(String),
+}
+
+/// Hits collected for an upload which haven't been saved yet.
+#[derive(Default)]
+struct Pending {
+    stats: AccessStats,
+    /// Whether the upload was missing the last time these were to be saved.
+    missing: bool,
+}
+
+/// Sends changes to the background thread once it has been started. Uploads are also removed
+/// outside of requests (e.g. by the reaper), so this is kept here rather than in a container.
+static UPDATES: Mutex<Option<Sender<Update>>> = Mutex::new(None);
+
+/// Sends hits to the background thread which saves them.
+pub struct StatsRecorder {
+    sender: Mutex<Sender<Update>>,
+}
+
+/// Contains the recorder used by all requests.
+#[derive(Copy, Clone)]
+pub struct StatsContainer;
+
+impl Key for StatsContainer {
+    type Value = StatsRecorder;
+}
+
+/// Classifies a client from its User-Agent.
+fn agent_class(agent: Option<&str>) -> &'static str {
+    let agent = match agent {
+        Some(agent) => agent.to_lowercase(),
+        None => return "other",
+    };
+
+    if BOT_MARKERS.iter().any(|x| agent.contains(x)) {
+        "bot"
+    } else if CLI_MARKERS.iter().any(|x| agent.starts_with(x)) {
+        "cli"
+    } else if MOBILE_MARKERS.iter().any(|x| agent.contains(x)) {
+        "mobile"
+    } else if agent.starts_with("mozilla/") {
+        "desktop"
+    } else {
+        "other"
+    }
+}
+
+impl Hit {
+    /// Describes an access of an upload from a request. Only the host of the referrer is kept,
+    /// and links from within this site aren't counted as referrers.
+    pub fn from_request(req: &Request, name: &str) -> Hit {
+        let referrer = match req.headers.get::<Referer>() {
+            Some(&Referer(ref referrer)) => match Url::parse(referrer) {
+                Ok(ref url) if url.host() != req.url.host() => Some(url.host().to_string()),
+                _ => None,
+            },
+            None => None,
+        };
+
+        let agent = match req.headers.get::<UserAgent>() {
+            Some(&UserAgent(ref agent)) => agent_class(Some(agent)),
+            None => agent_class(None),
+        };
+
+        Hit {
+            name: name.to_owned(),
+            time: Local::now().with_timezone(&FixedOffset::east(0)),
+            referrer,
+            agent,
+        }
+    }
+}
+
+impl StatsRecorder {
+    /// Queues a hit to be saved. This never blocks on disk access.
+    pub fn record(&self, hit: Hit) {
+        match self.sender.lock() {
+            Ok(sender) => match sender.send(Update::Hit(hit)) {
+                Err(e) => eprintln!("Failed to record hit: {}", e),
+                _ => (),
+            },
+            Err(_) => eprintln!("Failed to record hit: recorder is poisoned"),
+        }
+    }
+}
+
+impl AccessStats {
+    /// Parses the stats for an upload from the specified <root>/<name>.stats.json file. Uploads
+    /// which have never been accessed have no stats.
+    pub fn from_path(root: &str, name: &str) -> Option<AccessStats> {
+        let mut file = File::open(Path::new(root).join(format!("{}.stats.json", name))).ok()?;
+
+        let mut contents = String::new();
+        file.read_to_string(&mut contents).ok()?;
+
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Saves these stats to the specified <root>/<name>.stats.json file, atomically.
+    fn save(&self, root: &str, name: &str) -> io::Result<()> {
+        let contents = serde_json::to_string(self)?;
+
+        let path = Path::new(root).join(format!("{}.stats.json", name));
+        let temp_path = Path::new(root).join(format!("{}.stats.json.tmp", name));
+
+        File::create(&temp_path)?.write_all(contents.as_bytes())?;
+        fs::rename(&temp_path, &path)
+    }
+
+    /// Counts a single hit.
+    fn add(&mut self, hit: &Hit) {
+        self.hits += 1;
+
+        if self.last_access.map_or(true, |last| last < hit.time) {
+            self.last_access = Some(hit.time);
+        }
+
+        match hit.referrer {
+            Some(ref referrer) => *self.referrers.entry(referrer.to_owned()).or_insert(0) += 1,
+            None => (),
+        }
+
+        *self.agents.entry(hit.agent.to_string()).or_insert(0) += 1;
+    }
+
+    /// Adds hits which have been collected since these stats were last saved.
+    fn merge(&mut self, other: &AccessStats) {
+        self.hits += other.hits;
+
+        if self.last_access < other.last_access {
+            self.last_access = other.last_access;
+        }
+
+        for (referrer, hits) in &other.referrers {
+            *self.referrers.entry(referrer.to_owned()).or_insert(0) += hits;
+        }
+
+        for (agent, hits) in &other.agents {
+            *self.agents.entry(agent.to_owned()).or_insert(0) += hits;
+        }
+
+        while self.referrers.len() > MAX_REFERRERS {
+            let least = self
+                .referrers
+                .iter()
+                .min_by_key(|&(_, hits)| *hits)
+                .map(|(referrer, _)| referrer.to_owned());
+
+            match least {
+                Some(referrer) => self.referrers.remove(&referrer),
+                None => break,
+            };
+        }
+    }
+}
+
+/// Sends a change to the background thread, returning it back if the thread isn't running.
+fn send_update(update: Update) -> Result<(), Update> {
+    match *UPDATES.lock().unwrap_or_else(|x| x.into_inner()) {
+        Some(ref sender) => sender.send(update).map_err(|x| x.0),
+        None => Err(update),
+    }
+}
+
+/// Removes the stats of an upload, if it has any, along with any hits which haven't been saved.
+pub fn remove_stats(root: &str, name: &str) -> io::Result<()> {
+    match send_update(Update::Remove(name.to_owned())) {
+        Ok(_) => Ok(()),
+        Err(_) => remove_stats_file(root, name),
+    }
+}
+
+/// Moves the stats of an upload to a new name, if it has any, along with any hits which haven't
+/// been saved.
+pub fn rename_stats(root: &str, from: &str, to: &str) -> io::Result<()> {
+    match send_update(Update::Rename(from.to_owned(), to.to_owned())) {
+        Ok(_) => Ok(()),
+        Err(_) => rename_stats_file(root, from, to),
+    }
+}
+
+/// Removes the saved stats of an upload, if it has any.
+fn remove_stats_file(root: &str, name: &str) -> io::Result<()> {
+    match fs::remove_file(Path::new(root).join(format!("{}.stats.json", name))) {
+        Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// Moves the saved stats of an upload to a new name, if it has any.
+fn rename_stats_file(root: &str, from: &str, to: &str) -> io::Result<()> {
+    match fs::rename(
+        Path::new(root).join(format!("{}.stats.json", from)),
+        Path::new(root).join(format!("{}.stats.json", to)),
+    ) {
+        Err(ref e) if e.kind() == ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// Applies a change to the hits which haven't been saved yet, and to the saved stats.
+fn apply(root: &str, pending: &mut HashMap<String, Pending>, update: Update) {
+    match update {
+        Update::Hit(hit) => pending.entry(hit.name.clone()).or_default().stats.add(&hit),
+        Update::Rename(from, to) => {
+            match pending.remove(&from) {
+                Some(collected) => {
+                    pending.insert(to.clone(), collected);
+                }
+                None => (),
+            }
+
+            match rename_stats_file(root, &from, &to) {
+                Err(e) => eprintln!("Failed to move stats for {:?}: {}", from, e),
+                _ => (),
+            }
+        }
+        Update::Remove(name) => {
+            pending.remove(&name);
+
+            match remove_stats_file(root, &name) {
+                Err(e) => eprintln!("Failed to remove stats for {:?}: {}", name, e),
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Saves collected hits to the stats of each upload within the specified root.
+fn flush(root: &str, pending: &mut HashMap<String, Pending>) {
+    pending.retain(|name, collected| {
+        // Uploads which are being renamed are briefly missing, so are given until the next
+        // flush to reappear. Past that, they have been removed.
+        if !Path::new(root).join(format!("{}.info.json", name)).exists() {
+            let retry = !collected.missing;
+            collected.missing = true;
+            return retry;
+        }
+
+        let mut stats = AccessStats::from_path(root, name).unwrap_or_default();
+        stats.merge(&collected.stats);
+
+        match stats.save(root, name) {
+            Err(e) => eprintln!("Failed to save stats for {:?}: {}", name, e),
+            _ => (),
+        }
+
+        false
+    });
+}
+
+/// Starts a background thread which saves hits to uploads within the specified root,
+/// returning the recorder used to send hits to it.
+pub fn spawn_recorder(root: String) -> StatsRecorder {
+    let (sender, receiver) = mpsc::channel::<Update>();
+
+    thread::Builder::new()
+        .name("stats".into())
+        .spawn(move || {
+            let mut pending: HashMap<String, Pending> = HashMap::new();
+            let mut last_flush = Instant::now();
+
+            loop {
+                match receiver.recv_timeout(FLUSH_INTERVAL) {
+                    Ok(update) => apply(&root, &mut pending, update),
+                    Err(RecvTimeoutError::Timeout) => (),
+                    Err(RecvTimeoutError::Disconnected) => {
+                        flush(&root, &mut pending);
+                        return;
+                    }
+                }
+
+                if last_flush.elapsed() >= FLUSH_INTERVAL {
+                    flush(&root, &mut pending);
+                    last_flush = Instant::now();
+                }
+            }
+        })
+        .expect("Unable to start stats recorder");
+
+    *UPDATES.lock().unwrap_or_else(|x| x.into_inner()) = Some(sender.clone());
+
+    StatsRecorder {
+        sender: Mutex::new(sender),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(name: &str) -> Update {
+        Update::Hit(Hit {
+            name: name.to_owned(),
+            time: Local::now().with_timezone(&FixedOffset::east(0)),
+            referrer: None,
+            agent: "desktop",
+        })
+    }
+
+    fn hits(pending: &HashMap<String, Pending>, name: &str) -> u64 {
+        pending.get(name).map_or(0, |x| x.stats.hits)
+    }
+
+    #[test]
+    fn pending_hits_follow_renames() {
+        let root = std::env::temp_dir().join("liftpush-stats-rename");
+        let root = root.to_str().unwrap();
+        let mut pending = HashMap::new();
+
+        apply(root, &mut pending, hit("old"));
+        apply(root, &mut pending, hit("old"));
+        let rename = Update::Rename("old".into(), "new".into());
+        apply(root, &mut pending, rename);
+
+        assert_eq!(hits(&pending, "old"), 0);
+        assert_eq!(hits(&pending, "new"), 2);
+    }
+
+    #[test]
+    fn pending_hits_are_dropped_on_removal() {
+        let root = std::env::temp_dir().join("liftpush-stats-remove");
+        let root = root.to_str().unwrap();
+        let mut pending = HashMap::new();
+
+        apply(root, &mut pending, hit("name"));
+        apply(root, &mut pending, Update::Remove("name".into()));
+        apply(root, &mut pending, hit("other"));
+
+        assert_eq!(hits(&pending, "name"), 0);
+        assert_eq!(hits(&pending, "other"), 1);
+    }
+
+    #[test]
+    fn missing_uploads_are_kept_for_one_flush() {
+        let root = std::env::temp_dir().join("liftpush-stats-missing");
+        let root = root.to_str().unwrap();
+        let mut pending = HashMap::new();
+
+        apply(root, &mut pending, hit("missing"));
+
+        flush(root, &mut pending);
+        assert_eq!(hits(&pending, "missing"), 1);
+
+        flush(root, &mut pending);
+        assert_eq!(hits(&pending, "missing"), 0);
+    }
+}
@@ -2,8 +2,13 @@
 
 use std::error::Error;
 use std::fmt::{self, Debug, Display, Formatter};
+use std::fs;
 use std::fs::File;
+use std::fs::OpenOptions;
+use std::io::ErrorKind;
 use std::io::Read;
+use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 
 use chrono::DateTime;
@@ -12,6 +17,18 @@ use chrono::FixedOffset;
 use chrono::Local;
 use serde_json;
 
+use iron::mime::Mime;
+
+use mime_guess;
+
+use blobs;
+
+use derivatives::remove_derivatives;
+
+use stats::remove_stats;
+
+use usage;
+
 /// Used for representing generic String errors as IronErrors.
 #[derive(Debug)]
 pub struct StringError(pub String);
@@ -24,13 +41,86 @@ impl Display for StringError {
     }
 }
 
+/// The longest name which an upload can have.
+const MAX_NAME_LENGTH: usize = 64;
+
+/// Names which are used by endpoints or static files, and so can't be taken by uploads.
+const RESERVED_NAMES: &[&str] = &[
+    "css", "delete", "dl", "js", "listing", "login", "logout", "manage", "oembed", "qr", "raw",
+    "rename", "static", "upload",
+];
+
+/// Checks that a name is usable as the name of an upload. Only letters, digits, underscores
+/// and hyphens are allowed, so that names can't escape the upload directory, be confused with
+/// a file extension, or change the meaning of the URLs they are part of (e.g. a trailing "+"
+/// previews an upload instead of following it).
+pub fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_NAME_LENGTH
+        && name
+            .chars()
+            .all(|x| x.is_ascii_alphanumeric() || x == '_' || x == '-')
+}
+
+/// Checks if a name is used by an endpoint or static file, and so can't be given to an upload.
+pub fn is_reserved_name(name: &str) -> bool {
+    RESERVED_NAMES.contains(&name.to_lowercase().as_str())
+}
+
+/// A name which has been claimed for a new upload, by creating an empty metadata file in its
+/// place. If the upload fails before its metadata is saved, the name is released again.
+pub struct ReservedName {
+    root: String,
+    pub name: String,
+    saved: bool,
+}
+
+impl ReservedName {
+    /// Attempts to reserve the specified name in the specified root. Returns None if the name
+    /// is already in use.
+    pub fn reserve(root: &str, name: &str) -> Result<Option<ReservedName>, String> {
+        let path = Path::new(root).join(format!("{}.info.json", name));
+
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(_) => Ok(Some(ReservedName {
+                root: root.to_string(),
+                name: name.to_string(),
+                saved: false,
+            })),
+            Err(ref e) if e.kind() == ErrorKind::AlreadyExists => Ok(None),
+            Err(e) => Err(format!("File {} couldn't be reserved: {:?}", name, e)),
+        }
+    }
+
+    /// Saves metadata over the reservation, keeping the name.
+    pub fn save(mut self, meta: &FileMetadata) -> Result<(), String> {
+        meta.save(&self.root, &self.name)?;
+        self.saved = true;
+        Ok(())
+    }
+}
+
+impl Drop for ReservedName {
+    fn drop(&mut self) {
+        if !self.saved {
+            let path = Path::new(&self.root).join(format!("{}.info.json", self.name));
+
+            match fs::remove_file(&path) {
+                Err(e) => eprintln!("Failed to release name {:?}: {}", self.name, e),
+                _ => {}
+            }
+        }
+    }
+}
+
 /// The different file types supported.
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum FileType {
     File,
     Url,
     Text,
+    Collection,
 }
 
 impl FileType {
@@ -40,6 +130,7 @@ impl FileType {
             "file" => Some(FileType::File),
             "url" => Some(FileType::Url),
             "text" => Some(FileType::Text),
+            "collection" => Some(FileType::Collection),
             _ => None,
         }
     }
@@ -67,8 +158,39 @@ mod metadata_rfc2822 {
     }
 }
 
+/// Implements a RFC2822 serializer for serde for optional dates.
+mod metadata_rfc2822_option {
+    use chrono::{DateTime, FixedOffset};
+    use serde::{self, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(
+        date: &Option<DateTime<FixedOffset>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match *date {
+            Some(ref date) => serializer.serialize_str(&date.to_rfc2822()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<FixedOffset>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)? {
+            Some(s) => DateTime::parse_from_rfc2822(&s)
+                .map(Some)
+                .map_err(serde::de::Error::custom),
+            None => Ok(None),
+        }
+    }
+}
+
 /// The main metadata store for files.
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct FileMetadata {
     /// Date when this file was uploaded.
     #[serde(with = "metadata_rfc2822")]
@@ -89,6 +211,68 @@ pub struct FileMetadata {
     /// The original filename the user specified.
     /// If this is a file filetype, this is required.
     pub actual_filename: Option<String>,
+
+    /// The names of the files within this collection.
+    /// If this is a collection filetype, this is required.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub members: Option<Vec<String>>,
+
+    /// Date after which this file is no longer served, and will be removed.
+    #[serde(
+        default,
+        with = "metadata_rfc2822_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expires: Option<DateTime<FixedOffset>>,
+
+    /// How many times this file can be viewed before it is removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_views: Option<u64>,
+
+    /// How many times this file has been viewed, if it has a view limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub views: Option<u64>,
+
+    /// The size of this file's contents in bytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+
+    /// Identifies the API key this file was uploaded with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uploader: Option<String>,
+
+    /// The SHA3-256 hash of this file's contents, if it is stored as a shared blob.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+
+    /// The content type of this file, as detected from its contents when it was uploaded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mime: Option<String>,
+
+    /// The width of this file in pixels, if it is an image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub width: Option<u32>,
+
+    /// The height of this file in pixels, if it is an image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub height: Option<u32>,
+
+    /// The language a text file is highlighted as, if it was specified when uploaded.
+    /// Otherwise, the language is found from the file's extension.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+
+    /// Whether this short link always shows its destination before redirecting to it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interstitial: Option<bool>,
+
+    /// The salted hash of the password required to view this file, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+
+    /// The salted hash of the token which allows this file to be deleted without logging in.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_token: Option<String>,
 }
 
 impl FileMetadata {
@@ -118,6 +302,36 @@ impl FileMetadata {
         }
     }
 
+    /// Saves this FileMetadata to the specified <root>/<name>.info.json file. The file is
+    /// replaced atomically, so concurrent readers never see a partially written file.
+    pub fn save(&self, root: &str, name: &str) -> Result<(), String> {
+        let meta_string = match serde_json::to_string(self) {
+            Ok(data) => data,
+            Err(e) => return Err(format!("File {} couldn't be serialized: {:?}", name, e)),
+        };
+
+        let mut path = PathBuf::from(root);
+        path.push(name.to_string() + ".info.json");
+
+        let mut temp_path = PathBuf::from(root);
+        temp_path.push(name.to_string() + ".info.json.tmp");
+
+        let mut meta_file = match File::create(&temp_path) {
+            Ok(file) => file,
+            Err(e) => return Err(format!("File {} couldn't be created: {:?}", name, e)),
+        };
+
+        match meta_file.write_all(meta_string.as_bytes()) {
+            Ok(_) => (),
+            Err(e) => return Err(format!("File {} couldn't be written: {:?}", name, e)),
+        }
+
+        match fs::rename(&temp_path, &path) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(format!("File {} couldn't be replaced: {:?}", name, e)),
+        }
+    }
+
     /// Creates a new FileMetadata for a generic file upload.
     pub fn new_from_file(filename: String, actual_filename: String) -> FileMetadata {
         FileMetadata {
@@ -126,6 +340,20 @@ impl FileMetadata {
             filename: Some(filename),
             actual_filename: Some(actual_filename),
             url: None,
+            members: None,
+            expires: None,
+            max_views: None,
+            views: None,
+            size: None,
+            uploader: None,
+            hash: None,
+            mime: None,
+            width: None,
+            height: None,
+            language: None,
+            interstitial: None,
+            delete_token: None,
+            password: None,
         }
     }
 
@@ -137,6 +365,20 @@ impl FileMetadata {
             filename: Some(filename),
             actual_filename: Some(actual_filename),
             url: None,
+            members: None,
+            expires: None,
+            max_views: None,
+            views: None,
+            size: None,
+            uploader: None,
+            hash: None,
+            mime: None,
+            width: None,
+            height: None,
+            language: None,
+            interstitial: None,
+            delete_token: None,
+            password: None,
         }
     }
 
@@ -148,6 +390,185 @@ impl FileMetadata {
             filename: None,
             actual_filename: None,
             url: Some(url),
+            members: None,
+            expires: None,
+            max_views: None,
+            views: None,
+            size: None,
+            uploader: None,
+            hash: None,
+            mime: None,
+            width: None,
+            height: None,
+            language: None,
+            interstitial: None,
+            delete_token: None,
+            password: None,
         }
     }
+
+    /// Creates a new FileMetadata for a collection of other uploads.
+    pub fn new_from_collection(members: Vec<String>) -> FileMetadata {
+        FileMetadata {
+            date: Local::now().with_timezone(&FixedOffset::east(0)),
+            file_type: FileType::Collection,
+            filename: None,
+            actual_filename: None,
+            url: None,
+            members: Some(members),
+            expires: None,
+            max_views: None,
+            views: None,
+            size: None,
+            uploader: None,
+            hash: None,
+            mime: None,
+            width: None,
+            height: None,
+            language: None,
+            interstitial: None,
+            delete_token: None,
+            password: None,
+        }
+    }
+
+    /// Lists the names of all files which have metadata in the specified root.
+    pub fn list_names(root: &str) -> Result<Vec<String>, String> {
+        let paths = match fs::read_dir(root) {
+            Ok(paths) => paths,
+            Err(e) => return Err(format!("Directory {} couldn't be read: {:?}", root, e)),
+        };
+
+        let mut names = Vec::new();
+
+        for path in paths {
+            let path = match path {
+                Ok(path) => path,
+                Err(e) => return Err(format!("Directory {} couldn't be read: {:?}", root, e)),
+            };
+
+            let path_filename = path.file_name();
+            let filename = match path_filename.to_str() {
+                Some(filename) => filename,
+                None => continue,
+            };
+
+            if filename.ends_with(".info.json") {
+                let name: Vec<&str> = filename.split(".").collect::<Vec<_>>();
+                names.push(name[0..name.len() - 2].join("."));
+            }
+        }
+
+        Ok(names)
+    }
+
+    /// Returns the content type this file should be served with. Files uploaded before their
+    /// type was detected fall back to a guess from their extension.
+    pub fn content_type(&self) -> Mime {
+        match self.mime.as_ref().and_then(|mime| mime.parse().ok()) {
+            Some(mime) => mime,
+            None => match self.actual_filename {
+                Some(ref actual_filename) => mime_guess::guess_mime_type(actual_filename),
+                None => mime_guess::octet_stream(),
+            },
+        }
+    }
+
+    /// Returns true if this file has passed its expiry date.
+    pub fn is_expired(&self) -> bool {
+        match self.expires {
+            Some(expires) => expires <= Local::now().with_timezone(&FixedOffset::east(0)),
+            None => false,
+        }
+    }
+
+    /// Returns true if this file has a view limit, and has used up all of its views.
+    pub fn views_exhausted(&self) -> bool {
+        match self.max_views {
+            Some(max_views) => self.views.unwrap_or(0) >= max_views,
+            None => false,
+        }
+    }
+
+    /// Removes this file's contents (if any) and metadata from the specified root.
+    pub fn remove(&self, root: &str, name: &str) -> Result<(), String> {
+        match self.actual_filename {
+            Some(ref actual_filename) => {
+                match fs::remove_file(Path::new(root).join(actual_filename)) {
+                    Ok(_) => (),
+                    // The metadata should still be removed if the contents have gone missing
+                    Err(ref e) if e.kind() == ErrorKind::NotFound => (),
+                    Err(e) => return Err(format!("File {} couldn't be removed: {:?}", name, e)),
+                }
+            }
+            None => (),
+        }
+
+        match self.hash {
+            Some(ref hash) => match blobs::release(root, hash) {
+                Ok(_) => (),
+                Err(e) => return Err(format!("File {} couldn't be released: {:?}", name, e)),
+            },
+            None => (),
+        }
+
+        match remove_derivatives(root, name) {
+            Ok(_) => (),
+            Err(e) => return Err(format!("Images for {} couldn't be removed: {:?}", name, e)),
+        }
+
+        match remove_stats(root, name) {
+            Ok(_) => (),
+            Err(e) => return Err(format!("Stats for {} couldn't be removed: {:?}", name, e)),
+        }
+
+        match fs::remove_file(Path::new(root).join(format!("{}.info.json", name))) {
+            Ok(_) => (),
+            Err(e) => return Err(format!("File {} couldn't be removed: {:?}", name, e)),
+        }
+
+        // Only the storage is given back, as the upload was still made
+        match self.uploader {
+            Some(ref uploader) => usage::release(root, uploader, self.size.unwrap_or(0)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_names_are_valid() {
+        assert!(is_valid_name("CloudyGlass"));
+        assert!(is_valid_name("my_file-2"));
+        assert!(is_valid_name(&"a".repeat(MAX_NAME_LENGTH)));
+    }
+
+    #[test]
+    fn preview_suffix_is_invalid() {
+        assert!(!is_valid_name("name+"));
+        assert!(!is_valid_name("a+b"));
+        assert!(!is_valid_name("+"));
+    }
+
+    #[test]
+    fn other_names_are_invalid() {
+        assert!(!is_valid_name(""));
+        assert!(!is_valid_name(&"a".repeat(MAX_NAME_LENGTH + 1)));
+        assert!(!is_valid_name("../info"));
+        assert!(!is_valid_name("name.png"));
+        assert!(!is_valid_name("a b"));
+        assert!(!is_valid_name("a?b"));
+        assert!(!is_valid_name("a%2F"));
+        assert!(!is_valid_name("caf\u{e9}"));
+    }
+
+    #[test]
+    fn endpoint_names_are_reserved() {
+        assert!(is_reserved_name("upload"));
+        assert!(is_reserved_name("Raw"));
+        assert!(!is_reserved_name("uploads"));
+    }
 }
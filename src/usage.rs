@@ -0,0 +1,150 @@
+//! Records how much each API key has uploaded, so that its limits can be enforced. Usage is
+//! recorded as uploads are stored, rather than found from the uploads which still exist, so
+//! that removing an upload doesn't give back its place in the day's upload count.
+
+use std::fs;
+use std::fs::File;
+use std::io::ErrorKind;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::FixedOffset;
+use chrono::Local;
+
+use iron::prelude::*;
+use iron::status;
+
+use serde_json;
+
+use types::FileMetadata;
+use types::StringError;
+
+/// Held while usage is read and updated, so that concurrent uploads can't both claim the last
+/// of a limit.
+static USAGE_LOCK: Mutex<()> = Mutex::new(());
+
+/// How much an API key has uploaded.
+#[derive(Serialize, Deserialize, Default)]
+pub struct KeyUsage {
+    /// How many bytes are currently stored using the key.
+    pub stored_bytes: u64,
+    /// When each upload in the past 24 hours was made.
+    #[serde(default)]
+    pub uploads: Vec<DateTime<FixedOffset>>,
+}
+
+/// Returns the path of the usage record for the specified API key.
+fn usage_path(root: &str, uploader: &str) -> PathBuf {
+    Path::new(root)
+        .join("usage")
+        .join(format!("{}.json", uploader))
+}
+
+impl KeyUsage {
+    /// Finds the usage of a key which has no record yet from the uploads which it has made, so
+    /// that keys used before usage was recorded keep their limits.
+    fn from_uploads(root: &str, uploader: &str) -> Result<KeyUsage, String> {
+        let mut usage = KeyUsage::default();
+
+        for name in FileMetadata::list_names(root)? {
+            let meta = match FileMetadata::from_path(root, &name) {
+                Ok(meta) => meta,
+                // May have been removed since being listed
+                Err(_) => continue,
+            };
+
+            if meta.uploader.as_ref().map(|x| x.as_str()) != Some(uploader) {
+                continue;
+            }
+
+            usage.stored_bytes += meta.size.unwrap_or(0);
+            usage.uploads.push(meta.date);
+        }
+
+        Ok(usage)
+    }
+
+    /// Reads the usage of the specified key from its record.
+    fn from_path(root: &str, uploader: &str) -> Result<KeyUsage, String> {
+        let mut file = match File::open(usage_path(root, uploader)) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == ErrorKind::NotFound => {
+                return KeyUsage::from_uploads(root, uploader)
+            }
+            Err(e) => return Err(format!("Usage of {} couldn't be read: {:?}", uploader, e)),
+        };
+
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)
+            .map_err(|e| format!("Usage of {} couldn't be read: {:?}", uploader, e))?;
+
+        serde_json::from_str(&contents)
+            .map_err(|e| format!("Usage of {} couldn't be parsed: {:?}", uploader, e))
+    }
+
+    /// Saves the usage of the specified key to its record, atomically.
+    fn save(&self, root: &str, uploader: &str) -> Result<(), String> {
+        let contents = serde_json::to_string(self)
+            .map_err(|e| format!("Usage of {} couldn't be serialized: {:?}", uploader, e))?;
+
+        let path = usage_path(root, uploader);
+        let temp_path = path.with_extension("json.tmp");
+
+        fs::create_dir_all(Path::new(root).join("usage"))
+            .and_then(|_| File::create(&temp_path))
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .and_then(|_| fs::rename(&temp_path, &path))
+            .map_err(|e| format!("Usage of {} couldn't be saved: {:?}", uploader, e))
+    }
+
+    /// Forgets uploads which were made more than 24 hours ago.
+    fn prune(&mut self) {
+        let day_ago = Local::now().with_timezone(&FixedOffset::east(0)) - Duration::days(1);
+        self.uploads.retain(|x| *x > day_ago);
+    }
+}
+
+/// Updates the usage of the specified key while holding the usage lock. The changes are only
+/// saved if the update succeeds.
+pub fn update<T, F>(root: &str, uploader: &str, func: F) -> IronResult<T>
+where
+    F: FnOnce(&mut KeyUsage) -> IronResult<T>,
+{
+    let _guard = USAGE_LOCK.lock().unwrap_or_else(|x| x.into_inner());
+
+    let io_error = |x: String| {
+        IronError::new(
+            StringError(x),
+            (status::InternalServerError, "Internal I/O error"),
+        )
+    };
+
+    let mut usage = KeyUsage::from_path(root, uploader).map_err(io_error)?;
+    usage.prune();
+
+    let result = func(&mut usage)?;
+    usage.save(root, uploader).map_err(io_error)?;
+
+    Ok(result)
+}
+
+/// Finds how many uploads the specified key has made in the past 24 hours.
+pub fn uploads_today(root: &str, uploader: &str) -> IronResult<u64> {
+    update(root, uploader, |usage| Ok(usage.uploads.len() as u64))
+}
+
+/// Gives back the storage used by an upload which has been removed. The upload still counts
+/// towards the day's uploads.
+pub fn release(root: &str, uploader: &str, bytes: u64) -> Result<(), String> {
+    let _guard = USAGE_LOCK.lock().unwrap_or_else(|x| x.into_inner());
+
+    let mut usage = KeyUsage::from_path(root, uploader)?;
+    usage.prune();
+    usage.stored_bytes = usage.stored_bytes.saturating_sub(bytes);
+    usage.save(root, uploader)
+}
@@ -40,7 +40,8 @@ const app = new Vue({
         rename_value: "",
 
         showDelete: false,
-        showRename: false
+        showRename: false,
+        showQr: false
     },
     methods: {
         deleteFile: function(event) {